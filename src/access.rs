@@ -0,0 +1,489 @@
+use crate::{ImpVec, IndexError, NodeId};
+use core::ops::Range;
+use orx_pinned_vec::PinnedVec;
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Returns a reference to the element at the given `index`;
+    /// or the provided `default` reference if the `index` is out of bounds.
+    ///
+    /// This is the panic-free counterpart of indexing with `vec[index]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&["a", "b"]);
+    ///
+    /// let default = "-";
+    /// assert_eq!(vec.index_or(1, &default), &"b");
+    /// assert_eq!(vec.index_or(2, &default), &"-");
+    /// ```
+    pub fn index_or<'a>(&'a self, index: usize, default: &'a T) -> &'a T {
+        self.get(index).unwrap_or(default)
+    }
+
+    /// Returns a reference to the element at the given `index`;
+    /// or an [`IndexError`] carrying the `index` and the current length of the vector if the `index` is out of bounds.
+    ///
+    /// This is the non-panicking counterpart of indexing with `vec[index]`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['a', 'b']);
+    ///
+    /// assert_eq!(vec.try_index(1), Ok(&'b'));
+    ///
+    /// let error = vec.try_index(2).unwrap_err();
+    /// assert_eq!(error, IndexError { index: 2, len: 2 });
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "index out of bounds: the len is 2 but the index is 2"
+    /// );
+    /// ```
+    pub fn try_index(&self, index: usize) -> Result<&T, IndexError> {
+        self.get(index).ok_or(IndexError {
+            index,
+            len: self.len(),
+        })
+    }
+
+    /// Returns a reference to the element with the given type-safe [`NodeId`]; or None if it is out of bounds.
+    ///
+    /// This is equivalent to `vec.get(id.0)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['a', 'b']);
+    ///
+    /// assert_eq!(vec.get_node(NodeId(0)), Some(&'a'));
+    /// assert_eq!(vec.get_node(NodeId(2)), None);
+    /// ```
+    pub fn get_node(&self, id: NodeId) -> Option<&T> {
+        self.get(id.0)
+    }
+
+    /// Returns a reference to the element at the given `index` which is valid for the entire borrow of the imp-vec;
+    /// or None if the `index` is out of bounds.
+    ///
+    /// This is equivalent to `get` of the underlying pinned vector, since the reference returned by `get`
+    /// is already bound to the borrow of the imp-vec.
+    /// It is provided to spell out the lifetime in builder code next to [`imp_push_get_ref`]:
+    /// both references remain valid while new elements are pushed with a shared reference, since elements are never moved.
+    ///
+    /// [`imp_push_get_ref`]: crate::ImpVec::imp_push_get_ref
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// fn collect_refs<'a>(vec: &'a ImpVec<u32>) -> Vec<&'a u32> {
+    ///     (0..vec.len()).filter_map(|i| vec.get_ref_for_lifetime(i)).collect()
+    /// }
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1]);
+    ///
+    /// let refs = collect_refs(&vec);
+    /// vec.imp_extend_from_slice(&[2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(refs, [&0, &1]);
+    /// ```
+    pub fn get_ref_for_lifetime(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
+
+    /// Returns the elements within the given `range` as a contiguous slice, if possible.
+    ///
+    /// Returns:
+    /// * `Some(slice)` if the `range` lies within a single fragment of the underlying pinned vector,
+    /// * `None` if the `range` spans multiple fragments,
+    /// * `None` if the `range` is out of bounds.
+    ///
+    /// Note that an empty range within bounds always yields an empty slice;
+    /// and every valid range yields a slice when the vector is backed by a `FixedVec`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(vec.get_slice(1..3), Some([1, 2].as_slice()));
+    /// assert_eq!(vec.get_slice(4..7), Some([4, 5, 6].as_slice()));
+    /// assert_eq!(vec.get_slice(2..6), None); // spans two fragments
+    /// assert_eq!(vec.get_slice(5..8), None); // out of bounds
+    /// ```
+    pub fn get_slice(&self, range: Range<usize>) -> Option<&[T]> {
+        match range.start <= range.end && range.end <= self.len() {
+            false => None,
+            true => match range.is_empty() {
+                true => Some(&[]),
+                false => {
+                    let mut slices = self.slices(range).into_iter();
+                    match (slices.next(), slices.next()) {
+                        (Some(slice), None) => Some(slice),
+                        _ => None,
+                    }
+                }
+            },
+        }
+    }
+
+    /// Returns a mutable slice of the elements within the given `range`:
+    /// * `Some(slice)` if the `range` lies within a single fragment of the underlying pinned vector,
+    /// * `None` if the `range` spans multiple fragments,
+    /// * `None` if the `range` is out of bounds.
+    ///
+    /// This enables bulk in-place updates such as `copy_from_slice` or `fill` on sub-ranges of the vector.
+    ///
+    /// Note that an empty range within bounds always yields an empty slice;
+    /// and every valid range yields a slice when the vector is backed by a `FixedVec`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// vec.get_slice_mut(1..3).unwrap().copy_from_slice(&[10, 20]);
+    /// vec.get_slice_mut(4..7).unwrap().fill(42);
+    /// assert_eq!(&vec, [0, 10, 20, 3, 42, 42, 42].as_slice());
+    ///
+    /// assert!(vec.get_slice_mut(2..6).is_none()); // spans two fragments
+    /// assert!(vec.get_slice_mut(5..8).is_none()); // out of bounds
+    /// ```
+    pub fn get_slice_mut(&mut self, range: Range<usize>) -> Option<&mut [T]> {
+        get_slice_mut(&mut **self, range)
+    }
+
+    /// Returns the element at the given `index` together with the slice starting at this element and extending to the end of its fragment;
+    /// or None if the `index` is out of bounds.
+    ///
+    /// The first element of the returned slice is the element at `index`.
+    /// This allows to process the elements following the indexed element in batches without computing the fragment boundaries.
+    /// Note that the slice extends to the end of the vector when it is backed by a `FixedVec`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(vec.get_with_fragment(1), Some((&1, [1, 2, 3].as_slice())));
+    /// assert_eq!(vec.get_with_fragment(4), Some((&4, [4, 5, 6].as_slice())));
+    /// assert_eq!(vec.get_with_fragment(7), None);
+    /// ```
+    pub fn get_with_fragment(&self, index: usize) -> Option<(&T, &[T])> {
+        match index < self.len() {
+            false => None,
+            true => {
+                let slice = self.slices(index..self.len()).into_iter().next()?;
+                slice.first().map(|x| (x, slice))
+            }
+        }
+    }
+
+    /// Returns a pointer to the first element of the vector; or None if the vector is empty.
+    ///
+    /// Due to the pinned element guarantees of the underlying vector, the pointer remains valid
+    /// while new elements are appended to the vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// assert!(vec.first_ptr().is_none());
+    ///
+    /// vec.imp_push(42);
+    /// let ptr = vec.first_ptr().unwrap();
+    /// assert_eq!(ptr, &vec[0] as *const i32);
+    ///
+    /// vec.imp_extend_from_slice(&[0; 100]);
+    /// assert_eq!(unsafe { *ptr }, 42);
+    /// ```
+    pub fn first_ptr(&self) -> Option<*const T> {
+        // get_ptr is bounded by the capacity rather than the length
+        match self.is_empty() {
+            true => None,
+            false => self.get_ptr(0),
+        }
+    }
+
+    /// Returns a mutable pointer to the first element of the vector; or None if the vector is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::new();
+    /// assert!(vec.first_ptr_mut().is_none());
+    ///
+    /// vec.imp_extend_from_slice(&[0, 1, 2]);
+    /// let ptr = vec.first_ptr_mut().unwrap();
+    /// unsafe { *ptr = 42 };
+    ///
+    /// assert_eq!(&vec, [42, 1, 2].as_slice());
+    /// ```
+    pub fn first_ptr_mut(&mut self) -> Option<*mut T> {
+        match self.is_empty() {
+            true => None,
+            false => self.get_ptr_mut(0),
+        }
+    }
+
+    /// Returns a mutable reference to the last element of the vector; or None if the vector is empty.
+    ///
+    /// This is convenient to tweak the element which has just been pushed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::new();
+    /// assert!(vec.last_mut().is_none());
+    ///
+    /// vec.imp_extend_from_slice(&[0, 1, 2]);
+    /// if let Some(last) = vec.last_mut() {
+    ///     *last *= 10;
+    /// }
+    /// assert_eq!(&vec, [0, 1, 20].as_slice());
+    /// ```
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        let idx = self.len().checked_sub(1)?;
+        self.get_mut(idx)
+    }
+
+    /// Returns a mutable reference to the last element of the vector with a shared reference; or None if the vector is empty.
+    ///
+    /// This is the counterpart of [`last_mut`] for builders which push elements with a shared reference
+    /// and want to tweak the element which has just been pushed.
+    ///
+    /// [`last_mut`]: crate::ImpVec::last_mut
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe since it allows mutating an element with a shared reference to the vector.
+    /// The caller must guarantee that there exists no other reference to the last element while the returned mutable reference is alive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// let first = vec.imp_push_get_ref(0);
+    ///
+    /// vec.imp_push(1);
+    /// if let Some(last) = unsafe { vec.imp_last_mut() } {
+    ///     *last = 42;
+    /// }
+    ///
+    /// assert_eq!(first, &0);
+    /// assert_eq!(&vec, [0, 42].as_slice());
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn imp_last_mut(&self) -> Option<&mut T> {
+        let pinned = self.pinned_mut();
+        let idx = pinned.len().checked_sub(1)?;
+        let ptr = pinned.get_ptr_mut(idx)?;
+        // SAFETY: idx is in bounds; the caller guarantees that there is no other reference to the last element
+        Some(unsafe { &mut *ptr })
+    }
+
+    /// Returns the index of the element that the `ptr` points to;
+    /// or None if the `ptr` does not point to an element of this vector.
+    ///
+    /// This is the reverse of obtaining a pointer to the element at a given index with `get_ptr`.
+    /// Since positions of the elements are pinned, the pointer of an element never changes while the vector grows;
+    /// therefore, pointers can be round-tripped to indices at any time.
+    ///
+    /// The lookup is *O(1)* for a `FixedVec` and *O(f)* for a `SplitVec` where f is the number of fragments.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    ///
+    /// let ptr = vec.get_ptr(2).unwrap();
+    /// vec.imp_extend_from_slice(&[4, 5, 6, 7]);
+    /// assert_eq!(vec.index_from_ptr(ptr), Some(2));
+    ///
+    /// let other = 42;
+    /// assert_eq!(vec.index_from_ptr(&other as *const i32), None);
+    /// ```
+    pub fn index_from_ptr(&self, ptr: *const T) -> Option<usize> {
+        self.index_of_ptr(ptr)
+    }
+
+    /// Returns whether or not the `element` reference points to an element of this vector.
+    ///
+    /// The check is based on the memory address of the `element` rather than its value;
+    /// hence, an equal value stored elsewhere is not contained.
+    /// This is the boolean counterpart of [`index_from_ptr`], which avoids computing the index of the element.
+    ///
+    /// [`index_from_ptr`]: crate::ImpVec::index_from_ptr
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// let a = vec.imp_push_get_ref('a');
+    ///
+    /// let other = ImpVec::new();
+    /// let other_a = other.imp_push_get_ref('a');
+    ///
+    /// assert!(vec.contains_ref(a));
+    /// assert!(!vec.contains_ref(other_a));
+    /// ```
+    pub fn contains_ref(&self, element: &T) -> bool {
+        self.contains_reference(element)
+    }
+
+    /// Returns the memory of the element at the given `index` as a byte slice; or None if the `index` is out of bounds.
+    ///
+    /// The slice has a length of `size_of::<T>()` and points to the element in place; no bytes are copied.
+    /// The byte view reflects the in-memory representation of `T` on the target platform:
+    /// * the byte order of integers and floats is the native endianness, such as little-endian on x86 and most ARM targets;
+    /// * the layout of a type which is not `#[repr(C)]` or `#[repr(transparent)]` is unspecified and might change across compilations.
+    ///
+    /// Therefore, the byte view is suitable for hashing or serialization within a process, but not as a portable format.
+    ///
+    /// # Safety
+    ///
+    /// `T` must not contain any padding bytes, since reading uninitialized padding as `u8` is undefined behavior.
+    /// Primitive numeric types, arrays of them and `#[repr(C)]` structs of them without gaps satisfy this requirement.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[1u32, 0x01020304]);
+    ///
+    /// let bytes = unsafe { vec.element_bytes(1) }.unwrap();
+    /// assert_eq!(bytes.len(), 4);
+    /// assert_eq!(u32::from_ne_bytes(bytes.try_into().unwrap()), 0x01020304);
+    ///
+    /// assert!(unsafe { vec.element_bytes(2) }.is_none());
+    /// ```
+    pub unsafe fn element_bytes(&self, index: usize) -> Option<&[u8]>
+    where
+        T: Copy,
+    {
+        self.get(index).map(|x| {
+            let ptr = x as *const T as *const u8;
+            // SAFETY: the element is alive for the lifetime of the borrow and the caller guarantees that it has no padding bytes
+            unsafe { core::slice::from_raw_parts(ptr, core::mem::size_of::<T>()) }
+        })
+    }
+
+    /// Returns an iterator yielding pointers to the elements of the vector from the last to the first.
+    ///
+    /// This is convenient when unwinding a stack stored in the vector from its top.
+    /// Since positions of the elements are pinned, the pointers remain valid while the vector grows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1, 2]);
+    ///
+    /// let ptrs: Vec<_> = vec.iter_rev_ptr().collect();
+    /// let expected: Vec<_> = [2, 1, 0].map(|i| &vec[i] as *const i32).to_vec();
+    /// assert_eq!(ptrs, expected);
+    ///
+    /// vec.imp_extend_from_slice(&[3; 100]);
+    /// let values: Vec<_> = ptrs.iter().map(|p| unsafe { **p }).collect();
+    /// assert_eq!(values, [2, 1, 0]);
+    /// ```
+    pub fn iter_rev_ptr(&self) -> impl Iterator<Item = *const T> + '_ {
+        self.iter_rev().map(|x| x as *const T)
+    }
+
+    /// Returns mutable references to the two adjacent elements at positions `i` and `i + 1`;
+    /// or None if `i + 1` is out of bounds.
+    ///
+    /// This is convenient for relinking neighboring nodes of self-referential collections.
+    /// Since the two positions are adjacent, they are guaranteed to be different and the returned references never alias.
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe since it allows mutating elements with a shared reference to the vector.
+    /// The caller must guarantee that there exists no other reference to the two elements while the returned mutable references are alive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1, 2]);
+    ///
+    /// let (a, b) = unsafe { vec.get_adjacent_mut(1) }.unwrap();
+    /// core::mem::swap(a, b);
+    /// assert_eq!(&vec, [0, 2, 1].as_slice());
+    ///
+    /// assert!(unsafe { vec.get_adjacent_mut(2) }.is_none());
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn get_adjacent_mut(&self, i: usize) -> Option<(&mut T, &mut T)> {
+        let pinned = self.pinned_mut();
+        match i.checked_add(1) {
+            Some(j) if j < pinned.len() => {
+                let a = pinned.get_ptr_mut(i)?;
+                let b = pinned.get_ptr_mut(j)?;
+                // SAFETY: i and j are in bounds and different; hence, the references do not alias
+                Some(unsafe { (&mut *a, &mut *b) })
+            }
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn get_slice_mut<T, P: PinnedVec<T>>(
+    pinned: &mut P,
+    range: Range<usize>,
+) -> Option<&mut [T]> {
+    match range.start <= range.end && range.end <= pinned.len() {
+        false => None,
+        true => match range.is_empty() {
+            true => Some(&mut []),
+            false => {
+                let mut slices = pinned.slices_mut(range).into_iter();
+                match (slices.next(), slices.next()) {
+                    (Some(slice), None) => Some(slice),
+                    _ => None,
+                }
+            }
+        },
+    }
+}
@@ -0,0 +1,127 @@
+use crate::ImpVec;
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use core::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+use orx_pinned_vec::PinnedVec;
+
+/// A contiguous buffer of elements whose allocation is aligned to a given alignment,
+/// which is useful for SIMD loads; created by [`ImpVec::into_aligned_vec`].
+///
+/// Note that a standard `Vec<T>` cannot be used for this purpose since it always deallocates with the alignment of `T`.
+///
+/// The buffer dereferences to a slice `[T]`.
+pub struct AlignedVec<T: Copy> {
+    ptr: NonNull<T>,
+    len: usize,
+    align: usize,
+}
+
+impl<T: Copy> AlignedVec<T> {
+    /// Returns the alignment of the allocation, which is guaranteed to be at least `align_of::<T>()`.
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    fn layout(len: usize, align: usize) -> Layout {
+        Layout::array::<T>(len)
+            .and_then(|x| x.align_to(align))
+            .expect("capacity overflow")
+    }
+}
+
+// SAFETY: AlignedVec uniquely owns its buffer, similar to a Vec
+unsafe impl<T: Copy + Send> Send for AlignedVec<T> {}
+
+// SAFETY: AlignedVec does not provide interior mutability, similar to a Vec
+unsafe impl<T: Copy + Sync> Sync for AlignedVec<T> {}
+
+impl<T: Copy> Deref for AlignedVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: ptr is aligned and valid for len elements
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: Copy> DerefMut for AlignedVec<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: ptr is aligned and valid for len elements
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T: Copy> Drop for AlignedVec<T> {
+    fn drop(&mut self) {
+        let layout = Self::layout(self.len, self.align);
+        if layout.size() > 0 {
+            // SAFETY: the buffer is allocated with exactly this layout; elements are Copy and need not be dropped
+            unsafe { dealloc(self.ptr.as_ptr() as *mut u8, layout) };
+        }
+    }
+}
+
+impl<T: Copy + Debug> Debug for AlignedVec<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.deref(), f)
+    }
+}
+
+impl<T: Copy, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Consumes the vector and copies its elements into a contiguous [`AlignedVec`]
+    /// whose allocation is aligned to the maximum of `align` and `align_of::<T>()`.
+    ///
+    /// The alignment guarantee holds for the pointer to the first element; i.e., `vec.as_ptr() as usize % align == 0`.
+    /// This is also guaranteed for an empty vector, in which case no memory is allocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two, or if the required allocation size overflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0.0f32, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    ///
+    /// let aligned = vec.into_aligned_vec(64);
+    /// assert_eq!(aligned.as_ptr() as usize % 64, 0);
+    /// assert_eq!(&aligned[..], &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// ```
+    pub fn into_aligned_vec(self, align: usize) -> AlignedVec<T> {
+        assert!(
+            align.is_power_of_two(),
+            "alignment {} is not a power of two",
+            align
+        );
+        let align = align.max(core::mem::align_of::<T>());
+        let len = self.len();
+        let layout = AlignedVec::<T>::layout(len, align);
+
+        let ptr = match layout.size() {
+            // SAFETY: align is a non-zero power of two; the dangling pointer is never dereferenced for a zero size
+            0 => unsafe { NonNull::new_unchecked(align as *mut T) },
+            _ => {
+                // SAFETY: layout has a non-zero size
+                let ptr = unsafe { alloc(layout) } as *mut T;
+                let ptr = NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout));
+                let mut dst = ptr.as_ptr();
+                for slice in self.slices(..) {
+                    // SAFETY: the allocation has room for len elements, which is the sum of the slice lengths
+                    unsafe {
+                        core::ptr::copy_nonoverlapping(slice.as_ptr(), dst, slice.len());
+                        dst = dst.add(slice.len());
+                    }
+                }
+                ptr
+            }
+        };
+
+        AlignedVec { ptr, len, align }
+    }
+}
@@ -0,0 +1,119 @@
+use crate::ImpVec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use orx_fixed_vec::FixedVec;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::{Doubling, Linear, Recursive, SplitVec};
+
+/// A builder to configure the underlying pinned vector of an [`ImpVec`] fluently.
+///
+/// The builder is type-stated by the pinned vector `P` that it builds:
+/// * `doubling()` switches to a [`SplitVec<T, Doubling>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Doubling.html), which is also the default,
+/// * `recursive()` switches to a [`SplitVec<T, Recursive>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Recursive.html),
+/// * `linear(constant_fragment_capacity_exponent)` switches to a [`SplitVec<T, Linear>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Linear.html),
+/// * `fixed(fixed_capacity)` switches to a [`FixedVec<T>`](https://docs.rs/orx-fixed-vec/latest/orx_fixed_vec/).
+///
+/// Once switched to the recursive growth, `first_fragment_capacity(capacity)` sets the capacity of the first fragment.
+/// It is only available for the recursive growth, since the other growth strategies dictate the fragment capacities.
+///
+/// Finally, `build()` creates the imp-vec with the configured pinned vector.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_imp_vec::*;
+///
+/// let vec: ImpVec<char, SplitVec<char, Linear>> = ImpVecBuilder::new().linear(4).build();
+/// vec.imp_push('a');
+/// assert_eq!(vec.capacity(), 16);
+///
+/// let vec: ImpVec<char, SplitVec<char, Recursive>> = ImpVecBuilder::new()
+///     .recursive()
+///     .first_fragment_capacity(100)
+///     .build();
+/// assert_eq!(vec.capacity(), 100);
+///
+/// let vec: ImpVec<char, FixedVec<char>> = ImpVecBuilder::new().fixed(42).build();
+/// assert_eq!(vec.capacity(), 42);
+/// ```
+pub struct ImpVecBuilder<T, P = SplitVec<T, Doubling>>
+where
+    P: PinnedVec<T>,
+{
+    pinned_vec: P,
+    phantom: PhantomData<T>,
+}
+
+impl<T> ImpVecBuilder<T> {
+    /// Creates a new builder which builds an imp-vec backed by a [`SplitVec<T, Doubling>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Doubling.html) unless configured otherwise.
+    pub fn new() -> Self {
+        Self::from_pinned_vec(SplitVec::with_doubling_growth())
+    }
+}
+
+impl<T> Default for ImpVecBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, P: PinnedVec<T>> ImpVecBuilder<T, P> {
+    /// Configures the builder to create an imp-vec backed by a [`SplitVec<T, Doubling>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Doubling.html).
+    pub fn doubling(self) -> ImpVecBuilder<T, SplitVec<T, Doubling>> {
+        ImpVecBuilder::from_pinned_vec(SplitVec::with_doubling_growth())
+    }
+
+    /// Configures the builder to create an imp-vec backed by a [`SplitVec<T, Recursive>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Recursive.html).
+    pub fn recursive(self) -> ImpVecBuilder<T, SplitVec<T, Recursive>> {
+        ImpVecBuilder::from_pinned_vec(SplitVec::with_recursive_growth())
+    }
+
+    /// Configures the builder to create an imp-vec backed by a [`SplitVec<T, Linear>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Linear.html).
+    ///
+    /// * Each fragment of the underlying split vector will have a capacity of  `2 ^ constant_fragment_capacity_exponent`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fragment capacity `2 ^ constant_fragment_capacity_exponent` overflows `usize`;
+    /// see [`ImpVec::with_linear_growth`].
+    pub fn linear(
+        self,
+        constant_fragment_capacity_exponent: usize,
+    ) -> ImpVecBuilder<T, SplitVec<T, Linear>> {
+        let vec = ImpVec::with_linear_growth(constant_fragment_capacity_exponent);
+        ImpVecBuilder::from_pinned_vec(vec.into_inner())
+    }
+
+    /// Configures the builder to create an imp-vec backed by a [`FixedVec<T>`](https://docs.rs/orx-fixed-vec/latest/orx_fixed_vec/) with the given `fixed_capacity`.
+    pub fn fixed(self, fixed_capacity: usize) -> ImpVecBuilder<T, FixedVec<T>> {
+        ImpVecBuilder::from_pinned_vec(FixedVec::new(fixed_capacity))
+    }
+
+    /// Builds the imp-vec with the configured underlying pinned vector.
+    pub fn build(self) -> ImpVec<T, P> {
+        self.pinned_vec.into()
+    }
+
+    // helpers
+    fn from_pinned_vec(pinned_vec: P) -> Self {
+        Self {
+            pinned_vec,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> ImpVecBuilder<T, SplitVec<T, Recursive>> {
+    /// Sets the capacity of the first fragment of the underlying split vector with `Recursive` growth.
+    ///
+    /// Note that `Doubling` and `Linear` growth strategies determine the fragment capacities by definition;
+    /// and hence, this configuration is only available for the `Recursive` growth.
+    ///
+    /// A `capacity` of zero is ignored and the default first fragment capacity is used instead.
+    pub fn first_fragment_capacity(self, capacity: usize) -> Self {
+        match capacity {
+            0 => self.recursive(),
+            _ => Self::from_pinned_vec(Vec::with_capacity(capacity).into()),
+        }
+    }
+}
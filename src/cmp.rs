@@ -0,0 +1,186 @@
+use crate::ImpVec;
+use alloc::vec::Vec;
+use orx_pinned_vec::PinnedVec;
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Returns true if the `prefix` is a prefix of the vector.
+    ///
+    /// Elements are compared by index and the comparison short-circuits on the first mismatch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    ///
+    /// assert!(vec.starts_with(&[]));
+    /// assert!(vec.starts_with(&[0, 1]));
+    /// assert!(!vec.starts_with(&[1]));
+    /// assert!(!vec.starts_with(&[0, 1, 2, 3, 4]));
+    /// ```
+    pub fn starts_with(&self, prefix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        prefix.len() <= self.len() && prefix.iter().enumerate().all(|(i, x)| &self[i] == x)
+    }
+
+    /// Returns true if the `suffix` is a suffix of the vector.
+    ///
+    /// Elements are compared by index and the comparison short-circuits on the first mismatch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    ///
+    /// assert!(vec.ends_with(&[]));
+    /// assert!(vec.ends_with(&[2, 3]));
+    /// assert!(!vec.ends_with(&[2]));
+    /// assert!(!vec.ends_with(&[-1, 0, 1, 2, 3]));
+    /// ```
+    pub fn ends_with(&self, suffix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.len().checked_sub(suffix.len()) {
+            Some(begin) => suffix
+                .iter()
+                .enumerate()
+                .all(|(i, x)| &self[begin + i] == x),
+            None => false,
+        }
+    }
+
+    /// Returns true if the first `k` elements of the vector and the `other` vector are equal.
+    ///
+    /// The prefix is strict:
+    /// * returns false if either of the vectors has fewer than `k` elements,
+    /// * otherwise, returns true if and only if `self[i] == other[i]` for all `i` in `0..k`.
+    ///
+    /// Therefore, `eq_prefix(other, 0)` is always true.
+    /// Elements are compared by index and the comparison short-circuits on the first mismatch.
+    /// Note that the vectors are not required to have the same underlying pinned vector type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    ///
+    /// let other = ImpVec::with_fixed_capacity(3);
+    /// other.imp_extend_from_slice(&[0, 1, 42]);
+    ///
+    /// assert!(vec.eq_prefix(&other, 0));
+    /// assert!(vec.eq_prefix(&other, 2));
+    /// assert!(!vec.eq_prefix(&other, 3));
+    /// assert!(!vec.eq_prefix(&vec, 5)); // shorter than k
+    /// ```
+    pub fn eq_prefix<P2: PinnedVec<T>>(&self, other: &ImpVec<T, P2>, k: usize) -> bool
+    where
+        T: PartialEq,
+    {
+        k <= self.len() && k <= other.len() && (0..k).all(|i| self[i] == other[i])
+    }
+
+    /// Returns true if the vector and the `other` vector contain the same elements with the same multiplicities,
+    /// regardless of their order.
+    ///
+    /// The method collects references to the elements of both vectors and sorts them;
+    /// hence, it requires *O(n)* additional memory and runs in *O(n log n)* time.
+    /// Elements themselves are neither cloned nor moved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[3, 1, 2, 1]);
+    ///
+    /// let other = ImpVec::with_fixed_capacity(4);
+    /// other.imp_extend_from_slice(&[1, 1, 2, 3]);
+    /// assert!(vec.eq_unordered(&other));
+    ///
+    /// let other = ImpVec::with_fixed_capacity(4);
+    /// other.imp_extend_from_slice(&[1, 2, 2, 3]);
+    /// assert!(!vec.eq_unordered(&other));
+    /// ```
+    pub fn eq_unordered<P2: PinnedVec<T>>(&self, other: &ImpVec<T, P2>) -> bool
+    where
+        T: Ord,
+    {
+        if self.len() != other.len() {
+            return false;
+        }
+        let mut a: Vec<_> = self.iter().collect();
+        let mut b: Vec<_> = other.iter().collect();
+        a.sort_unstable();
+        b.sort_unstable();
+        a == b
+    }
+}
+
+impl<P: PinnedVec<f64>> ImpVec<f64, P> {
+    /// Returns true if the vector and the `other` vector have equal lengths and
+    /// all of their elements at the same positions differ by at most `epsilon`.
+    ///
+    /// Note that `NaN` is not approximately equal to any value, including itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let a: ImpVec<f64> = ImpVec::new();
+    /// a.imp_extend_from_slice(&[0.1 + 0.2, 1.0]);
+    ///
+    /// let b: ImpVec<f64> = ImpVec::new();
+    /// b.imp_extend_from_slice(&[0.3, 1.0]);
+    ///
+    /// assert!(a.approx_eq(&b, 1e-9));
+    /// assert!(!a.approx_eq(&b, 0.0));
+    /// ```
+    pub fn approx_eq<P2: PinnedVec<f64>>(&self, other: &ImpVec<f64, P2>, epsilon: f64) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+}
+
+impl<P: PinnedVec<f32>> ImpVec<f32, P> {
+    /// Returns true if the vector and the `other` vector have equal lengths and
+    /// all of their elements at the same positions differ by at most `epsilon`.
+    ///
+    /// Note that `NaN` is not approximately equal to any value, including itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let a = ImpVec::new();
+    /// a.imp_extend_from_slice(&[0.1f32 + 0.2, 1.0]);
+    ///
+    /// let b = ImpVec::new();
+    /// b.imp_extend_from_slice(&[0.3f32, 1.0]);
+    ///
+    /// assert!(a.approx_eq(&b, 1e-6));
+    /// ```
+    pub fn approx_eq<P2: PinnedVec<f32>>(&self, other: &ImpVec<f32, P2>, epsilon: f32) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(a, b)| (a - b).abs() <= epsilon)
+    }
+}
@@ -15,3 +15,51 @@ impl<T: Debug, P: PinnedVec<T> + Debug> Debug for ImpVec<T, P> {
         write!(f, "]")
     }
 }
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Writes a shallow debug representation of the vector to the formatter,
+    /// where each element is represented by its index and memory address, such as `[0: 0x1000, 1: 0x1008]`.
+    ///
+    /// Unlike `Debug`, this method never calls into the `Debug` implementation of the elements.
+    /// Therefore, it is safe to use for self-referential collections where following references
+    /// of the elements might never terminate; and it can be used even when `T` does not implement `Debug`.
+    /// Addresses can be matched with the references held by the elements to reason about the links.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    /// use core::fmt::{Debug, Formatter, Result};
+    ///
+    /// struct Node<'a> {
+    ///     next: Option<&'a Node<'a>>,
+    /// }
+    ///
+    /// struct Nodes<'a>(ImpVec<Node<'a>>);
+    ///
+    /// impl<'a> Debug for Nodes<'a> {
+    ///     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    ///         self.0.debug_shallow(f)
+    ///     }
+    /// }
+    ///
+    /// let nodes = Nodes(ImpVec::new());
+    /// let first = nodes.0.imp_push_get_ref(Node { next: None });
+    /// nodes.0.imp_push(Node { next: Some(first) });
+    ///
+    /// let repr = format!("{:?}", nodes);
+    /// assert!(repr.starts_with("[0: 0x"));
+    /// assert!(repr.contains(", 1: 0x"));
+    /// ```
+    pub fn debug_shallow(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[")?;
+        let mut iter = self.iter().enumerate();
+        if let Some((i, x)) = iter.next() {
+            write!(f, "{}: {:p}", i, x)?;
+            for (i, x) in iter {
+                write!(f, ", {}: {:p}", i, x)?;
+            }
+        }
+        write!(f, "]")
+    }
+}
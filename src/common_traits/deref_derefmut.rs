@@ -11,6 +11,7 @@ impl<T, P: PinnedVec<T>> Deref for ImpVec<T, P> {
 
 impl<T, P: PinnedVec<T>> DerefMut for ImpVec<T, P> {
     fn deref_mut(&mut self) -> &mut Self::Target {
+        self.forget_capacity();
         self.pinned_mut()
     }
 }
@@ -1,13 +1,20 @@
 use crate::imp_vec::ImpVec;
 use alloc::vec::Vec;
 use orx_fixed_vec::FixedVec;
-use orx_pinned_vec::PinnedVec;
+use orx_pinned_vec::{Collection, PinnedVec};
 use orx_split_vec::{Growth, SplitVec};
 
 // imp
 
 impl<T: PartialEq, P1: PinnedVec<T>, P2: PinnedVec<T>> PartialEq<ImpVec<T, P2>> for ImpVec<T, P1> {
     fn eq(&self, other: &ImpVec<T, P2>) -> bool {
+        // an imp-vec compared against itself through aliased references: elements are not compared
+        if core::ptr::eq(
+            self as *const _ as *const (),
+            other as *const _ as *const (),
+        ) {
+            return true;
+        }
         self.len() == other.len() && self.iter().zip(other.iter()).all(|(x, y)| x == y)
     }
 }
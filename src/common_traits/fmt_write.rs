@@ -0,0 +1,40 @@
+use crate::imp_vec::ImpVec;
+use core::fmt::{Error, Result, Write};
+use orx_pinned_vec::PinnedVec;
+
+/// Writing to an imp-vec of chars appends the chars of the written string to the end of the vector.
+///
+/// Since the chars are appended by `imp_push`, the writer only requires a shared reference to the vector;
+/// therefore, earlier references to the elements of the vector can be held while writing.
+///
+/// `write_str` returns an error if the vector is sealed.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_imp_vec::*;
+/// use core::fmt::Write;
+///
+/// let vec = ImpVec::new();
+/// write!(&vec, "x={}", 42).unwrap();
+///
+/// let first = &vec[0];
+/// write!(&vec, ", y={}", 7).unwrap();
+///
+/// assert_eq!(vec.len(), 9);
+/// assert_eq!(&vec, ['x', '=', '4', '2', ',', ' ', 'y', '=', '7'].as_slice());
+/// assert_eq!(first, &'x');
+/// ```
+impl<P: PinnedVec<char>> Write for &ImpVec<char, P> {
+    fn write_str(&mut self, s: &str) -> Result {
+        match self.is_sealed() {
+            true => Err(Error),
+            false => {
+                for c in s.chars() {
+                    self.imp_push(c);
+                }
+                Ok(())
+            }
+        }
+    }
+}
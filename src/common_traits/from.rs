@@ -1,4 +1,5 @@
 use crate::imp_vec::ImpVec;
+use alloc::collections::VecDeque;
 use orx_pinned_vec::PinnedVec;
 
 impl<T, P: PinnedVec<T>> From<P> for ImpVec<T, P> {
@@ -6,6 +7,19 @@ impl<T, P: PinnedVec<T>> From<P> for ImpVec<T, P> {
         Self {
             pinned_vec: pinned_vec.into(),
             phantom: Default::default(),
+            on_grow: Default::default(),
+            sealed: Default::default(),
+            next_fragment: Default::default(),
+            has_fixed_capacity: Default::default(),
         }
     }
 }
+
+impl<T, P: PinnedVec<T>> From<ImpVec<T, P>> for VecDeque<T> {
+    fn from(imp_vec: ImpVec<T, P>) -> Self {
+        let pinned_vec = imp_vec.into_inner();
+        let mut deque = VecDeque::with_capacity(pinned_vec.len());
+        deque.extend(pinned_vec);
+        deque
+    }
+}
@@ -1,4 +1,4 @@
-use crate::imp_vec::ImpVec;
+use crate::{imp_vec::ImpVec, NodeId};
 use core::ops::{Index, IndexMut};
 use orx_pinned_vec::PinnedVec;
 
@@ -19,3 +19,19 @@ impl<T, P: PinnedVec<T>> IndexMut<usize> for ImpVec<T, P> {
         self.get_mut(index).expect(OOB)
     }
 }
+
+impl<T, P: PinnedVec<T>> Index<NodeId> for ImpVec<T, P> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, id: NodeId) -> &Self::Output {
+        &self[id.0]
+    }
+}
+
+impl<T, P: PinnedVec<T>> IndexMut<NodeId> for ImpVec<T, P> {
+    #[inline(always)]
+    fn index_mut(&mut self, id: NodeId) -> &mut Self::Output {
+        &mut self[id.0]
+    }
+}
@@ -0,0 +1,41 @@
+use crate::imp_vec::ImpVec;
+use orx_pinned_vec::PinnedVec;
+use std::io::{Error, Result, Write};
+
+/// Writing to an imp-vec of bytes appends the bytes to the end of the vector.
+///
+/// Since the bytes are appended by `imp_extend_up_to_capacity`, the writer only requires a shared reference to the vector;
+/// therefore, earlier references to the elements of the vector can be held while writing.
+///
+/// * `write` appends as many bytes as fit into the capacity of the vector and returns the number of bytes appended.
+///   All bytes are appended unless the vector has a fixed capacity, such as a `FixedVec`.
+/// * `write` returns an error if the vector is sealed.
+/// * `flush` is a no-op.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_imp_vec::*;
+/// use std::io::Write;
+///
+/// let vec = ImpVec::new();
+/// write!(&vec, "x={}", 42).unwrap();
+///
+/// let first = &vec[0];
+/// write!(&vec, ", y={}", 7).unwrap();
+///
+/// assert_eq!(&vec, b"x=42, y=7".as_slice());
+/// assert_eq!(first, &b'x');
+/// ```
+impl<P: PinnedVec<u8>> Write for &ImpVec<u8, P> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        match self.is_sealed() {
+            true => Err(Error::other("cannot write to a sealed imp-vec")),
+            false => Ok(self.imp_extend_up_to_capacity(buf)),
+        }
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
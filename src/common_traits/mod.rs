@@ -1,7 +1,11 @@
 mod debug;
 mod deref_derefmut;
 mod eq;
+mod fmt_write;
 mod from;
 mod from_iter;
 mod index;
+mod into_con_iter;
 mod into_iter;
+#[cfg(feature = "std")]
+mod io_write;
@@ -0,0 +1,122 @@
+use crate::ImpVec;
+use core::cell::Cell;
+use orx_pinned_vec::PinnedVec;
+
+/// A builder to create self-referential, possibly cyclic, collections in an imp-vec without writing any `unsafe` code.
+///
+/// The builder is only accessible within the closure passed to [`ImpVec::build_cyclic`].
+/// It never hands out references to the elements;
+/// instead, elements are identified by their indices and linked to each other by pointers.
+/// This guarantees that the mutable reference created while linking two elements does not alias with any other reference.
+/// To keep this guarantee, the builder cannot be used to push or link elements from within the `set` closure passed to
+/// [`CyclicBuilder::link`]; such reentrant calls panic.
+///
+/// Since positions of the elements are pinned, the pointers set by the builder remain valid as long as the vector is alive,
+/// regardless of the further elements pushed to it.
+pub struct CyclicBuilder<'a, T, P: PinnedVec<T>> {
+    vec: &'a ImpVec<T, P>,
+    linking: Cell<bool>,
+}
+
+impl<T, P: PinnedVec<T>> CyclicBuilder<'_, T, P> {
+    /// Pushes the `value` to the vector and returns its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from within the `set` closure of [`CyclicBuilder::link`].
+    pub fn push(&self, value: T) -> usize {
+        self.assert_not_linking();
+        self.vec.imp_push(value);
+        self.vec.len() - 1
+    }
+
+    /// Returns the number of elements pushed so far.
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns true if no element is pushed yet.
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Links the element at position `from` to the element at position `to`:
+    /// `set` is called with a mutable reference to the element at `from` and a pointer to the element at `to`.
+    ///
+    /// `from` and `to` can be equal, which allows an element to point to itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of `from` or `to` is out of bounds,
+    /// or if called from within the `set` closure of another `link` call.
+    pub fn link<S>(&self, from: usize, set: S, to: usize)
+    where
+        S: FnOnce(&mut T, *const T),
+    {
+        self.assert_not_linking();
+        assert!(
+            from < self.len() && to < self.len(),
+            "out-of-bounds: cannot link {} to {} when the len is {}",
+            from,
+            to,
+            self.len()
+        );
+        // both pointers are obtained without creating references to the elements;
+        // therefore, the `to` pointer remains valid after `from` is mutably borrowed, even when `from == to`
+        let pinned = self.vec.pinned_mut();
+        let to = pinned.get_ptr(to).expect("in bounds");
+        let from = pinned.get_ptr_mut(from).expect("in bounds");
+        self.linking.set(true);
+        // SAFETY: the builder never hands out references to the elements, and it rejects push and link calls
+        // while `set` runs; hence, the only live reference is the mutable reference to the `from` element.
+        set(unsafe { &mut *from }, to);
+        self.linking.set(false);
+    }
+
+    fn assert_not_linking(&self) {
+        assert!(
+            !self.linking.get(),
+            "cannot push or link from within the `set` closure of `CyclicBuilder::link`"
+        );
+    }
+}
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Creates an imp-vec wrapping the given `pinned` vector and builds a self-referential, possibly cyclic,
+    /// collection in it by the build function `f`, without requiring any `unsafe` code.
+    ///
+    /// `f` receives a [`CyclicBuilder`] which allows to push elements and to link elements to each other by pointers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// struct Node {
+    ///     value: char,
+    ///     next: *const Node,
+    /// }
+    ///
+    /// // a -> b -> a
+    /// let vec = ImpVec::build_cyclic(SplitVec::new(), |b| {
+    ///     let x = b.push(Node { value: 'a', next: core::ptr::null() });
+    ///     let y = b.push(Node { value: 'b', next: core::ptr::null() });
+    ///     b.link(x, |node, next| node.next = next, y);
+    ///     b.link(y, |node, next| node.next = next, x);
+    /// });
+    ///
+    /// assert_eq!(vec.index_from_ptr(vec[0].next), Some(1));
+    /// assert_eq!(vec.index_from_ptr(vec[1].next), Some(0));
+    /// ```
+    pub fn build_cyclic<F>(pinned: P, f: F) -> Self
+    where
+        F: FnOnce(&CyclicBuilder<T, P>),
+    {
+        let vec: Self = pinned.into();
+        f(&CyclicBuilder {
+            vec: &vec,
+            linking: Cell::new(false),
+        });
+        vec
+    }
+}
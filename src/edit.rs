@@ -0,0 +1,427 @@
+use crate::{access::get_slice_mut, ImpVec};
+use alloc::vec::Vec;
+use core::ops::Range;
+use orx_pinned_vec::PinnedVec;
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Removes and returns the first element of the vector; or returns None if the vector is empty.
+    ///
+    /// This allows to consume the vector like a queue.
+    /// However, note that all remaining elements are shifted to the left;
+    /// and hence, the method has a time complexity of *O(n)*.
+    ///
+    /// Every remaining element moves one position to the left, and hence, to a new memory location.
+    /// Pointers that the elements hold to each other are not updated; after the call, each such pointer would refer to
+    /// the successor of its original target. Therefore, this method is not suitable for self-referential collections.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['a', 'b']);
+    ///
+    /// assert_eq!(vec.remove_first(), Some('a'));
+    /// assert_eq!(vec.remove_first(), Some('b'));
+    /// assert_eq!(vec.remove_first(), None);
+    /// ```
+    pub fn remove_first(&mut self) -> Option<T> {
+        remove_first(&mut **self)
+    }
+
+    /// Retains only the elements for which `pred` returns true, passing a mutable reference to each element.
+    ///
+    /// This is the counterpart of `Vec::retain_mut`: the edits applied by `pred` are kept for the retained elements,
+    /// and the retained elements are compacted to the front of the vector preserving their order.
+    ///
+    /// Once an element is removed, each retained element after it is swapped into an earlier position.
+    /// If the elements hold pointers to each other, these pointers are neither updated nor cleared,
+    /// and the ones targeting the removed elements dangle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[1, 2, 3, 4, 5]);
+    ///
+    /// vec.retain_mut(|x| match *x % 2 == 0 {
+    ///     true => false,
+    ///     false => {
+    ///         *x *= 10;
+    ///         true
+    ///     }
+    /// });
+    /// assert_eq!(&vec, [10, 30, 50].as_slice());
+    /// ```
+    pub fn retain_mut<F>(&mut self, pred: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        retain_mut(&mut **self, pred)
+    }
+
+    /// Swap-removes the element at position `index` from this vector and pushes it to the `dst` vector;
+    /// returns whether or not the element is moved.
+    ///
+    /// The removed element is replaced by the last element of this vector; and hence, removal is *O(1)*.
+    /// Pushing to `dst` requires only a shared reference; therefore, references to the elements of `dst` remain valid.
+    ///
+    /// Within this vector, only the element at `index` and the last element change positions.
+    /// An element of this vector holding a pointer to the moved element would then refer to the former last element,
+    /// and the one holding a pointer to the former last element would refer to a vacant position.
+    ///
+    /// Returns false leaving both vectors unchanged if:
+    /// * the `index` is out of bounds, or
+    /// * the element cannot be pushed to `dst`, since `dst` is [`seal`]ed or it has a fixed capacity which is full.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut src = ImpVec::new();
+    /// src.imp_extend_from_slice(&['a', 'b', 'c', 'd']);
+    ///
+    /// let dst = ImpVec::with_fixed_capacity(2);
+    /// let x = dst.imp_push_get_ref('x');
+    ///
+    /// assert!(src.move_element_to(1, &dst));
+    /// assert!(!src.move_element_to(3, &dst)); // out of bounds
+    /// assert!(!src.move_element_to(0, &dst)); // dst is full
+    ///
+    /// assert_eq!(&src, ['a', 'd', 'c'].as_slice());
+    /// assert_eq!(&dst, ['x', 'b'].as_slice());
+    /// assert_eq!(x, &'x');
+    /// ```
+    pub fn move_element_to<P2: PinnedVec<T>>(&mut self, index: usize, dst: &ImpVec<T, P2>) -> bool {
+        if index >= self.len() || dst.can_push(1).is_err() {
+            return false;
+        }
+        match self.swap_remove_get(index) {
+            Some((value, _)) => {
+                dst.imp_push(value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the element at position `index` by replacing it with the last element of the vector, and returns:
+    /// * the removed element, and
+    /// * a reference to the element which is moved into position `index`; or None if the removed element was the last element.
+    ///
+    /// Returns None if the `index` is out of bounds.
+    ///
+    /// This does not preserve the ordering of the remaining elements, but it is *O(1)*.
+    /// Only the last element moves; pointers held by the other elements to the last element are not updated,
+    /// and hence, they must not exist when this method is called.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['a', 'b', 'c', 'd']);
+    ///
+    /// assert_eq!(vec.swap_remove_get(1), Some(('b', Some(&'d'))));
+    /// assert_eq!(vec.swap_remove_get(2), Some(('c', None)));
+    /// assert_eq!(vec.swap_remove_get(2), None);
+    /// assert_eq!(&vec, ['a', 'd'].as_slice());
+    /// ```
+    pub fn swap_remove_get(&mut self, index: usize) -> Option<(T, Option<&T>)> {
+        swap_remove_get(&mut **self, index)
+    }
+
+    /// Removes the elements at the given `indices` and returns them in the order of their positions in the vector;
+    /// the remaining elements are compacted preserving their order.
+    ///
+    /// The `indices` are not required to be sorted or unique: they are sorted and deduplicated internally.
+    /// Indices which are out of bounds are ignored; hence, the number of returned elements might be less than the number of `indices`.
+    ///
+    /// Elements preceding the smallest removed index keep their positions, while all elements following it are shifted to the left.
+    /// Pointers that the elements hold to the shifted or removed elements are not updated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['a', 'b', 'c', 'd', 'e']);
+    ///
+    /// let removed = vec.remove_indices(&[3, 1, 3, 42]);
+    /// assert_eq!(removed, ['b', 'd']);
+    /// assert_eq!(&vec, ['a', 'c', 'e'].as_slice());
+    /// ```
+    pub fn remove_indices(&mut self, indices: &[usize]) -> Vec<T> {
+        remove_indices(&mut **self, indices)
+    }
+
+    /// Takes the vector out of `self` and returns it, leaving behind an empty imp-vec backed by `P::default()`.
+    ///
+    /// This is the counterpart of `core::mem::take` for the imp-vec;
+    /// the returned vector carries over all state of the original vector, including the `on_grow` callback and the sealed flag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1, 2]);
+    ///
+    /// let taken = vec.take();
+    /// assert!(vec.is_empty());
+    /// assert_eq!(&taken, [0, 1, 2].as_slice());
+    ///
+    /// vec.imp_push(42);
+    /// assert_eq!(&vec, [42].as_slice());
+    /// ```
+    pub fn take(&mut self) -> ImpVec<T, P>
+    where
+        P: Default,
+    {
+        core::mem::replace(self, P::default().into())
+    }
+
+    /// Overwrites the elements within the given `range` with the `values`, dropping the replaced elements.
+    ///
+    /// The length of the vector does not change and the elements are written in place;
+    /// therefore, positions of all elements, and hence pointers to them, remain valid.
+    /// The range may span multiple fragments of the underlying pinned vector.
+    ///
+    /// The `values` are collected before any element is replaced; hence, the vector is not modified when the method panics.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `range` is out of bounds, or if the number of `values` is not equal to the length of the `range`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// vec.replace_range(2..6, [20, 30, 40, 50]);
+    /// assert_eq!(&vec, [0, 1, 20, 30, 40, 50, 6].as_slice());
+    /// ```
+    pub fn replace_range<I>(&mut self, range: Range<usize>, values: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        replace_range(&mut **self, range, values)
+    }
+
+    /// Overwrites the elements starting at position `start` with the elements of `src` by a single `copy_from_slice`.
+    ///
+    /// The target range `start..start + src.len()` must lie within a single fragment of the underlying pinned vector,
+    /// which can be checked by [`get_slice`]; see [`replace_range`] for ranges spanning multiple fragments.
+    ///
+    /// [`get_slice`]: crate::ImpVec::get_slice
+    /// [`replace_range`]: crate::ImpVec::replace_range
+    ///
+    /// # Panics
+    ///
+    /// Panics if the target range is out of bounds or if it spans multiple fragments.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// vec.copy_from_slice_at(4, &[40, 50]);
+    /// assert_eq!(&vec, [0, 1, 2, 3, 40, 50, 6].as_slice());
+    /// ```
+    pub fn copy_from_slice_at(&mut self, start: usize, src: &[T])
+    where
+        T: Copy,
+    {
+        copy_from_slice_at(&mut **self, start, src)
+    }
+
+    /// Rearranges the elements of the vector in place with respect to the permutation `perm`,
+    /// such that the element at position `i` after the call is the element which was at position `perm[i]` before the call.
+    ///
+    /// This allows to physically reorder the elements with the indices obtained by [`argsort`] or [`argsort_by`].
+    ///
+    /// Every element which is not a fixed point of the permutation is moved to a new memory location.
+    /// Since pointers that the elements hold to each other are not remapped by the permutation,
+    /// a self-referential collection should rather be visited in the order of the indices, such as the ones returned by [`argsort`],
+    /// leaving the elements in place.
+    ///
+    /// [`argsort`]: crate::ImpVec::argsort
+    /// [`argsort_by`]: crate::ImpVec::argsort_by
+    ///
+    /// # Panics
+    ///
+    /// Panics if `perm` is not a permutation of `0..self.len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['c', 'a', 'd', 'b']);
+    ///
+    /// let perm = vec.argsort();
+    /// vec.apply_permutation(&perm);
+    /// assert_eq!(&vec, ['a', 'b', 'c', 'd'].as_slice());
+    /// ```
+    pub fn apply_permutation(&mut self, perm: &[usize]) {
+        apply_permutation(&mut **self, perm)
+    }
+}
+
+// shared by `ImpVec` and `EditableImpVec`, which only differ in how they access the pinned vector
+
+pub(crate) fn remove_first<T, P: PinnedVec<T>>(pinned: &mut P) -> Option<T> {
+    match pinned.is_empty() {
+        true => None,
+        false => Some(pinned.remove(0)),
+    }
+}
+
+pub(crate) fn retain_mut<T, P: PinnedVec<T>, F>(pinned: &mut P, mut pred: F)
+where
+    F: FnMut(&mut T) -> bool,
+{
+    let mut num_retained = 0;
+    for i in 0..pinned.len() {
+        if pred(&mut pinned[i]) {
+            if i != num_retained {
+                pinned.swap(num_retained, i);
+            }
+            num_retained += 1;
+        }
+    }
+    pinned.truncate(num_retained);
+}
+
+pub(crate) fn swap_remove_get<T, P: PinnedVec<T>>(
+    pinned: &mut P,
+    index: usize,
+) -> Option<(T, Option<&T>)> {
+    let last = pinned.len().checked_sub(1)?;
+    if index > last {
+        return None;
+    }
+    if index != last {
+        pinned.swap(index, last);
+    }
+    let value = pinned.pop()?;
+    Some((value, pinned.get(index)))
+}
+
+pub(crate) fn remove_indices<T, P: PinnedVec<T>>(pinned: &mut P, indices: &[usize]) -> Vec<T> {
+    let len = pinned.len();
+    let mut indices: Vec<_> = indices.iter().copied().filter(|i| *i < len).collect();
+    indices.sort_unstable();
+    indices.dedup();
+
+    let mut removed = Vec::with_capacity(indices.len());
+    let Some(&first) = indices.first() else {
+        return removed;
+    };
+
+    let mut tail = Vec::with_capacity(len - first);
+    while pinned.len() > first {
+        tail.extend(pinned.pop());
+    }
+
+    let mut indices = indices.into_iter().peekable();
+    for (i, x) in (first..len).zip(tail.into_iter().rev()) {
+        match indices.next_if_eq(&i) {
+            Some(_) => removed.push(x),
+            None => pinned.push(x),
+        }
+    }
+    removed
+}
+
+pub(crate) fn replace_range<T, P: PinnedVec<T>, I>(pinned: &mut P, range: Range<usize>, values: I)
+where
+    I: IntoIterator<Item = T>,
+{
+    assert!(
+        range.start <= range.end && range.end <= pinned.len(),
+        "range {:?} is out of bounds for the len {}",
+        range,
+        pinned.len()
+    );
+    let values: Vec<_> = values.into_iter().collect();
+    assert_eq!(
+        values.len(),
+        range.len(),
+        "number of values must be equal to the length of the range"
+    );
+
+    let mut values = values.into_iter();
+    for slice in pinned.slices_mut(range) {
+        for (x, value) in slice.iter_mut().zip(&mut values) {
+            *x = value;
+        }
+    }
+}
+
+pub(crate) fn copy_from_slice_at<T: Copy, P: PinnedVec<T>>(
+    pinned: &mut P,
+    start: usize,
+    src: &[T],
+) {
+    let range = start..start.saturating_add(src.len());
+    assert!(
+        range.end <= pinned.len(),
+        "range {:?} is out of bounds for the len {}",
+        range,
+        pinned.len()
+    );
+    get_slice_mut(pinned, range)
+        .expect("range must lie within a single fragment")
+        .copy_from_slice(src);
+}
+
+pub(crate) fn apply_permutation<T, P: PinnedVec<T>>(pinned: &mut P, perm: &[usize]) {
+    let len = pinned.len();
+    assert_eq!(
+        perm.len(),
+        len,
+        "permutation of length {} cannot be applied to a vector of length {}",
+        perm.len(),
+        len
+    );
+
+    let mut visited = alloc::vec![false; len];
+    for &p in perm {
+        assert!(
+            p < len && !core::mem::replace(&mut visited[p], true),
+            "perm is not a permutation of 0..{}",
+            len
+        );
+    }
+
+    visited.fill(false);
+    for start in 0..len {
+        let mut i = start;
+        while !visited[i] {
+            visited[i] = true;
+            let j = perm[i];
+            if j == start {
+                break;
+            }
+            pinned.swap(i, j);
+            i = j;
+        }
+    }
+}
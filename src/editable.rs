@@ -0,0 +1,209 @@
+use crate::{access::get_slice_mut, edit, ImpVec};
+use alloc::vec::Vec;
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut, Range},
+};
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::SplitVec;
+
+/// An editable counterpart of the [`ImpVec`] which allows operations changing the positions or the length of the vector,
+/// such as `insert`, `remove`, `pop` or `truncate`, but not the appending with a shared reference.
+///
+/// In addition to the methods of the underlying pinned vector, the editable vector provides the editing methods of the imp-vec,
+/// such as [`remove_first`], [`retain_mut`], [`remove_indices`] or [`apply_permutation`];
+/// while it does not provide any of the `imp_*` methods which append with a shared reference.
+/// The imp-vec itself keeps its editing methods, which require a mutable reference.
+/// Therefore, the split is not a type-state guarantee; it rather makes the editing phase of a collection explicit in its type,
+/// such as in a field or a function signature which must not append elements with a shared reference.
+///
+/// [`remove_first`]: crate::EditableImpVec::remove_first
+/// [`retain_mut`]: crate::EditableImpVec::retain_mut
+/// [`remove_indices`]: crate::EditableImpVec::remove_indices
+/// [`apply_permutation`]: crate::EditableImpVec::apply_permutation
+///
+/// An imp-vec is converted into an editable vector by [`ImpVec::into_editable`]; and back into an imp-vec by [`EditableImpVec::into_appendable`].
+/// Both conversions are free, they only move the underlying pinned vector.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_imp_vec::*;
+///
+/// let vec = ImpVec::new();
+/// let first = vec.imp_push_get_ref(0);
+/// vec.imp_extend_from_slice(&[1, 2, 3]);
+/// assert_eq!(first, &0);
+///
+/// let mut vec = vec.into_editable();
+/// vec.insert(0, 42);
+/// assert_eq!(vec.remove(1), 0);
+/// assert_eq!(vec.pop(), Some(3));
+/// vec.retain_mut(|x| *x != 2);
+///
+/// let vec = vec.into_appendable();
+/// vec.imp_push(7);
+/// assert_eq!(&vec, [42, 1, 7].as_slice());
+/// ```
+///
+/// Appending with a shared reference is not available on the editable vector:
+///
+/// ```compile_fail
+/// use orx_imp_vec::*;
+///
+/// let vec: EditableImpVec<i32> = ImpVec::new().into_editable();
+/// vec.imp_push(42);
+/// ```
+pub struct EditableImpVec<T, P = SplitVec<T>>
+where
+    P: PinnedVec<T>,
+{
+    pinned_vec: P,
+    phantom: PhantomData<T>,
+}
+
+impl<T, P: PinnedVec<T>> EditableImpVec<T, P> {
+    /// Converts the editable vector back into an appendable [`ImpVec`],
+    /// allowing to push elements with a shared reference again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::new().into_editable();
+    /// vec.push('a');
+    ///
+    /// let vec = vec.into_appendable();
+    /// let a = &vec[0];
+    /// vec.imp_push('b');
+    /// assert_eq!(a, &'a');
+    /// ```
+    pub fn into_appendable(self) -> ImpVec<T, P> {
+        self.pinned_vec.into()
+    }
+
+    /// Converts the editable vector into the underlying pinned vector.
+    pub fn into_inner(self) -> P {
+        self.pinned_vec
+    }
+}
+
+impl<T, P: PinnedVec<T>> EditableImpVec<T, P> {
+    /// Removes and returns the first element of the vector; or returns None if the vector is empty.
+    ///
+    /// See [`ImpVec::remove_first`] for details.
+    pub fn remove_first(&mut self) -> Option<T> {
+        edit::remove_first(&mut self.pinned_vec)
+    }
+
+    /// Retains only the elements for which `pred` returns true, passing a mutable reference to each element.
+    ///
+    /// See [`ImpVec::retain_mut`] for details.
+    pub fn retain_mut<F>(&mut self, pred: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        edit::retain_mut(&mut self.pinned_vec, pred)
+    }
+
+    /// Removes the element at position `index` by replacing it with the last element of the vector, and returns
+    /// the removed element together with a reference to the element moved into position `index`.
+    ///
+    /// See [`ImpVec::swap_remove_get`] for details.
+    pub fn swap_remove_get(&mut self, index: usize) -> Option<(T, Option<&T>)> {
+        edit::swap_remove_get(&mut self.pinned_vec, index)
+    }
+
+    /// Removes the elements at the given `indices` and returns them in the order of their positions in the vector.
+    ///
+    /// See [`ImpVec::remove_indices`] for details.
+    pub fn remove_indices(&mut self, indices: &[usize]) -> Vec<T> {
+        edit::remove_indices(&mut self.pinned_vec, indices)
+    }
+
+    /// Rearranges the elements of the vector in place with respect to the permutation `perm`.
+    ///
+    /// See [`ImpVec::apply_permutation`] for details.
+    pub fn apply_permutation(&mut self, perm: &[usize]) {
+        edit::apply_permutation(&mut self.pinned_vec, perm)
+    }
+
+    /// Overwrites the elements within the given `range` with the `values`, dropping the replaced elements.
+    ///
+    /// See [`ImpVec::replace_range`] for details.
+    pub fn replace_range<I>(&mut self, range: Range<usize>, values: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        edit::replace_range(&mut self.pinned_vec, range, values)
+    }
+
+    /// Overwrites the elements starting at position `start` with the elements of `src` by a single `copy_from_slice`.
+    ///
+    /// See [`ImpVec::copy_from_slice_at`] for details.
+    pub fn copy_from_slice_at(&mut self, start: usize, src: &[T])
+    where
+        T: Copy,
+    {
+        edit::copy_from_slice_at(&mut self.pinned_vec, start, src)
+    }
+
+    /// Returns the elements within the given `range` as a mutable slice if the range lies within a single fragment;
+    /// None otherwise.
+    ///
+    /// See [`ImpVec::get_slice_mut`] for details.
+    pub fn get_slice_mut(&mut self, range: Range<usize>) -> Option<&mut [T]> {
+        get_slice_mut(&mut self.pinned_vec, range)
+    }
+
+    /// Returns a mutable reference to the last element of the vector; or None if the vector is empty.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        let idx = self.len().checked_sub(1)?;
+        self.get_mut(idx)
+    }
+}
+
+impl<T, P: PinnedVec<T>> Deref for EditableImpVec<T, P> {
+    type Target = P;
+    fn deref(&self) -> &Self::Target {
+        &self.pinned_vec
+    }
+}
+
+impl<T, P: PinnedVec<T>> DerefMut for EditableImpVec<T, P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.pinned_vec
+    }
+}
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Converts the imp-vec into an [`EditableImpVec`] which allows operations changing the positions or the length of the vector,
+    /// such as `insert`, `remove`, `pop` or `truncate`.
+    ///
+    /// The conversion consumes the imp-vec; hence, there exists no outstanding reference to its elements.
+    /// The same holds for any method of the imp-vec requiring a mutable reference;
+    /// see [`EditableImpVec`] for the purpose of the separate type.
+    ///
+    /// Note that the sealed state, the grow callback and a pending next fragment capacity of the imp-vec
+    /// are not carried over to the editable vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['a', 'b', 'c']);
+    ///
+    /// let mut vec = vec.into_editable();
+    /// vec.truncate(1);
+    /// assert_eq!(vec.len(), 1);
+    /// ```
+    pub fn into_editable(self) -> EditableImpVec<T, P> {
+        EditableImpVec {
+            pinned_vec: self.into_inner(),
+            phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use core::fmt::Display;
+
+/// Error returned when an element is accessed with an index which is out of bounds of the vector.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexError {
+    /// The out-of-bounds index that is attempted to be accessed.
+    pub index: usize,
+    /// Length of the vector at the time of the access.
+    pub len: usize,
+}
+
+impl Display for IndexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "index out of bounds: the len is {} but the index is {}",
+            self.len, self.index
+        )
+    }
+}
+
+impl core::error::Error for IndexError {}
+
+/// Error returned by the fallible operations of the imp-vec.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImpVecError {
+    /// The operation requires more elements than the fixed capacity of the underlying pinned vector can hold.
+    CapacityExceeded {
+        /// Fixed capacity of the underlying pinned vector.
+        capacity: usize,
+        /// Number of elements that the operation requires the vector to hold.
+        needed: usize,
+    },
+    /// An element is accessed with an index which is out of bounds of the vector.
+    IndexOutOfBounds {
+        /// The out-of-bounds index that is attempted to be accessed.
+        index: usize,
+        /// Length of the vector at the time of the access.
+        len: usize,
+    },
+    /// An element is attempted to be pushed to a sealed vector; see [`ImpVec::seal`](crate::ImpVec::seal).
+    Sealed,
+}
+
+impl Display for ImpVecError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::CapacityExceeded { capacity, needed } => write!(
+                f,
+                "capacity exceeded: the fixed capacity is {} but {} elements are needed",
+                capacity, needed
+            ),
+            Self::IndexOutOfBounds { index, len } => write!(
+                f,
+                "index out of bounds: the len is {} but the index is {}",
+                len, index
+            ),
+            Self::Sealed => write!(f, "cannot push to a sealed imp-vec"),
+        }
+    }
+}
+
+impl core::error::Error for ImpVecError {}
+
+impl From<IndexError> for ImpVecError {
+    fn from(value: IndexError) -> Self {
+        Self::IndexOutOfBounds {
+            index: value.index,
+            len: value.len,
+        }
+    }
+}
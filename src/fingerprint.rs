@@ -0,0 +1,110 @@
+use crate::ImpVec;
+use core::hash::{Hash, Hasher};
+use orx_pinned_vec::PinnedVec;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// 64-bit FNV-1a hasher with a fixed seed.
+///
+/// Integers are written in little-endian byte order and `usize`/`isize` values are widened to 64 bits,
+/// so that the hash does not depend on the platform.
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes())
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64)
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write_u16(i as u16)
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write_u32(i as u32)
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64)
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write_u128(i as u128)
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64)
+    }
+}
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Returns a stable 64-bit fingerprint of the vector computed over its length and its elements in order.
+    ///
+    /// Unlike hashing with the standard library's `RandomState`, the fingerprint uses the FNV-1a hash with a fixed seed;
+    /// therefore, it is reproducible across runs and it is independent of the underlying pinned vector.
+    /// Equal contents always lead to equal fingerprints, which makes it convenient to be used as a caching key.
+    ///
+    /// Note that the fingerprint is as stable as the `Hash` implementation of `T`.
+    /// Further, it is not a cryptographic hash and must not be used where collisions can be exploited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&["a", "b", "c"]);
+    ///
+    /// let mut other = ImpVec::with_fixed_capacity(3);
+    /// other.imp_extend_from_slice(&["a", "b", "c"]);
+    ///
+    /// assert_eq!(vec.fingerprint(), other.fingerprint());
+    ///
+    /// other[2] = "x";
+    /// assert_ne!(vec.fingerprint(), other.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64
+    where
+        T: Hash,
+    {
+        let mut hasher = StableHasher::default();
+        hasher.write_usize(self.len());
+        for x in self.iter() {
+            x.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
@@ -0,0 +1,87 @@
+use crate::ImpVec;
+use core::ops::{Deref, Index, IndexMut};
+use orx_fixed_vec::FixedVec;
+
+/// An [`ImpVec`] backed by a [`FixedVec`] whose capacity `CAP` is part of the type.
+///
+/// The vector dereferences to the wrapped `ImpVec<T, FixedVec<T>>`; and hence, provides all its methods such as
+/// `imp_push` or `try_imp_push`. However, it cannot be mutably dereferenced, which guarantees that the underlying
+/// fixed vector is never replaced by one with a different capacity.
+///
+/// Since the capacity is known at compile time, it is available as the associated constant [`FixedImpVec::CAPACITY`],
+/// and [`is_full`] requires no runtime capacity lookup.
+///
+/// [`is_full`]: FixedImpVec::is_full
+///
+/// # Example
+///
+/// ```rust
+/// use orx_imp_vec::*;
+///
+/// let vec = FixedImpVec::<char, 3>::new();
+/// assert_eq!(vec.capacity(), 3);
+///
+/// let a = vec.imp_push_get_ref('a');
+/// vec.imp_extend_from_slice(&['b', 'c']);
+/// assert!(vec.is_full());
+/// assert_eq!(a, &'a');
+///
+/// assert_eq!(
+///     vec.try_imp_push('d'),
+///     Err(ImpVecError::CapacityExceeded { capacity: 3, needed: 4 })
+/// );
+/// ```
+#[derive(Debug)]
+pub struct FixedImpVec<T, const CAP: usize> {
+    imp_vec: ImpVec<T, FixedVec<T>>,
+}
+
+impl<T, const CAP: usize> FixedImpVec<T, CAP> {
+    /// Capacity of the vector, which is fixed by the type.
+    pub const CAPACITY: usize = CAP;
+
+    /// Creates a new empty vector allocating exactly `CAP` elements.
+    pub fn new() -> Self {
+        Self {
+            imp_vec: ImpVec::with_fixed_capacity(CAP),
+        }
+    }
+
+    /// Returns true if the vector contains `CAP` elements; i.e., if no more elements can be pushed.
+    pub fn is_full(&self) -> bool {
+        self.imp_vec.len() == CAP
+    }
+
+    /// Converts the vector into the wrapped `ImpVec<T, FixedVec<T>>`.
+    pub fn into_inner(self) -> ImpVec<T, FixedVec<T>> {
+        self.imp_vec
+    }
+}
+
+impl<T, const CAP: usize> Default for FixedImpVec<T, CAP> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const CAP: usize> Deref for FixedImpVec<T, CAP> {
+    type Target = ImpVec<T, FixedVec<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.imp_vec
+    }
+}
+
+impl<T, const CAP: usize> Index<usize> for FixedImpVec<T, CAP> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.imp_vec[index]
+    }
+}
+
+impl<T, const CAP: usize> IndexMut<usize> for FixedImpVec<T, CAP> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.imp_vec[index]
+    }
+}
@@ -0,0 +1,501 @@
+use crate::ImpVec;
+use alloc::{string::String, vec::Vec};
+use core::{fmt::Write, ops::Range};
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::{Growth, Recursive, SplitVec, SplitVecSlice};
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Returns an iterator over the elements of the vector where each element is paired with a boolean marker.
+    /// The marker is `true` if the element is the first element of a fragment of the underlying pinned vector;
+    /// `false` otherwise.
+    ///
+    /// This allows to observe where the fragment breaks occur without relying on the internals of the pinned vector.
+    /// Note that a `FixedVec` is a single contiguous fragment; therefore, only the very first element is marked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let marks: Vec<_> = vec.iter_with_fragment_marks().map(|(mark, _)| mark).collect();
+    /// assert_eq!(marks, [true, false, false, false, true, false]);
+    /// ```
+    pub fn iter_with_fragment_marks(&self) -> impl Iterator<Item = (bool, &T)> {
+        self.slices(..)
+            .into_iter()
+            .flat_map(|slice| slice.iter().enumerate().map(|(i, x)| (i == 0, x)))
+    }
+
+    /// Returns an iterator over the populated fragments of the underlying pinned vector as slices,
+    /// starting from the first fragment.
+    ///
+    /// The fragments are always yielded in their logical order, fragment 0 first;
+    /// this order is guaranteed to remain stable across versions, so that snapshots of the fragments are reproducible.
+    /// Hence, concatenating the slices yields the elements of the vector in their logical order.
+    /// Note that a `FixedVec` has a single fragment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let fragments: Vec<_> = vec.fragments_iter().collect();
+    /// assert_eq!(fragments, [&[0, 1, 2, 3][..], &[4, 5][..]]);
+    /// ```
+    pub fn fragments_iter(&self) -> impl Iterator<Item = &[T]> {
+        self.slices(..).into_iter().filter(|x| !x.is_empty())
+    }
+
+    /// Returns an iterator over the populated fragments of the underlying pinned vector as mutable slices,
+    /// starting from the first fragment.
+    ///
+    /// The slices are disjoint; therefore, they can be handed over to different threads to be processed concurrently,
+    /// such as by spawning a scoped thread per fragment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// std::thread::scope(|s| {
+    ///     for fragment in vec.fragments_iter_mut() {
+    ///         s.spawn(move || fragment.iter_mut().for_each(|x| *x *= 10));
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(&vec, [0, 10, 20, 30, 40, 50].as_slice());
+    /// ```
+    pub fn fragments_iter_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        self.slices_mut(..).into_iter().filter(|x| !x.is_empty())
+    }
+
+    /// Returns an iterator over the populated fragments of the underlying pinned vector as slices,
+    /// starting from the last fragment.
+    ///
+    /// Note that only the order of the fragments is reversed; each slice itself is in forward order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let fragments: Vec<_> = vec.fragments_iter_rev().collect();
+    /// assert_eq!(fragments, [&[4, 5][..], &[0, 1, 2, 3][..]]);
+    /// ```
+    pub fn fragments_iter_rev(&self) -> impl Iterator<Item = &[T]> {
+        let fragments: Vec<_> = self.fragments_iter().collect();
+        fragments.into_iter().rev()
+    }
+
+    /// Moves all elements of this vector, preserving their order, into a new imp-vec which has a single contiguous fragment;
+    /// and leaves this vector empty.
+    ///
+    /// This is useful to relayout a vector, whose elements are scattered into many fragments, for a cache-friendly final pass.
+    ///
+    /// Note that a `SplitVec` with `Doubling` growth cannot have an arbitrarily sized single fragment;
+    /// therefore, the result is backed by a `SplitVec` with [`Recursive`] growth.
+    ///
+    /// # Safety
+    ///
+    /// Elements are moved to new memory locations.
+    /// This is safe in terms of the borrow checker since the method requires a `&mut self` reference;
+    /// however, the method must not be used when the elements hold references to each other, since such references would be invalidated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(vec.fragments_iter().count(), 2);
+    ///
+    /// let compact = vec.compact();
+    /// assert!(vec.is_empty());
+    ///
+    /// let fragments: Vec<_> = compact.fragments_iter().collect();
+    /// assert_eq!(fragments, [&[0, 1, 2, 3, 4, 5][..]]);
+    /// ```
+    pub fn compact(&mut self) -> ImpVec<T, SplitVec<T, Recursive>> {
+        self.forget_capacity();
+        let pinned_vec = core::mem::replace(self.pinned_vec.get_mut(), P::pseudo_default());
+        match pinned_vec.len() {
+            0 => ImpVec::with_recursive_growth(),
+            len => {
+                let mut vec = Vec::with_capacity(len);
+                vec.extend(pinned_vec);
+                SplitVec::from(vec).into()
+            }
+        }
+    }
+}
+
+impl<T, G: Growth> ImpVec<T, SplitVec<T, G>> {
+    /// Returns the capacity of the first fragment of the underlying split vector;
+    /// or None if no fragment is allocated yet.
+    ///
+    /// This is helpful to verify that the sizing of the vector, such as the one configured by the [`ImpVecBuilder`],
+    /// is aligned with the typical batch size of the pushes.
+    ///
+    /// [`ImpVecBuilder`]: crate::ImpVecBuilder
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec: ImpVec<char, _> = ImpVec::with_doubling_growth();
+    /// assert_eq!(vec.first_fragment_capacity(), Some(4));
+    ///
+    /// let vec: ImpVec<char, _> = ImpVec::with_linear_growth(10);
+    /// assert_eq!(vec.first_fragment_capacity(), Some(1024));
+    /// ```
+    pub fn first_fragment_capacity(&self) -> Option<usize> {
+        self.fragments().first().map(|x| x.capacity())
+    }
+
+    /// Returns the location of the element at the given logical `index` as a tuple of
+    /// (fragment index, index within the fragment) of the underlying split vector;
+    /// or None if the `index` is out of bounds.
+    ///
+    /// The location is computed in constant time for [`Doubling`] and [`Linear`] growth strategies,
+    /// and by a linear search over the fragments for [`Recursive`] growth.
+    ///
+    /// [`Doubling`]: crate::Doubling
+    /// [`Linear`]: crate::Linear
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(vec.fragment_and_inner_index(1), Some((0, 1)));
+    /// assert_eq!(vec.fragment_and_inner_index(5), Some((1, 1)));
+    /// assert_eq!(vec.fragment_and_inner_index(6), None);
+    /// ```
+    pub fn fragment_and_inner_index(&self, index: usize) -> Option<(usize, usize)> {
+        self.growth()
+            .get_fragment_and_inner_indices(self.len(), self.fragments(), index)
+    }
+
+    /// Tries to return the elements within the given `range` as a contiguous slice by delegating to the underlying split vector; returns:
+    /// * `SplitVecSlice::Ok(slice)` if the `range` lies within a single fragment,
+    /// * `SplitVecSlice::Fragmented(first, last)` with the indices of the first and last fragments if the `range` spans multiple fragments,
+    /// * `SplitVecSlice::OutOfBounds` if the `range` is out of bounds.
+    ///
+    /// The returned slice is bounded by the shared borrow of the imp-vec; hence, elements can still be pushed while it is alive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(vec.split_slice(1..3), SplitVecSlice::Ok(&[1, 2]));
+    /// assert_eq!(vec.split_slice(2..6), SplitVecSlice::Fragmented(0, 1));
+    /// assert_eq!(vec.split_slice(5..8), SplitVecSlice::OutOfBounds);
+    /// ```
+    pub fn split_slice(&self, range: Range<usize>) -> SplitVecSlice<'_, T> {
+        self.pinned_mut().try_get_slice(range)
+    }
+
+    /// Creates a new imp-vec by mapping each element of this vector with `f`,
+    /// where the fragments of the created vector mirror the fragments of this vector.
+    ///
+    /// In other words, the `i`-th fragment of the result has the same length and capacity as the `i`-th fragment of this vector.
+    /// This is useful when the locality of the source layout is desired to be preserved, which would be lost by `iter().map(f).collect()`.
+    ///
+    /// Since the fragment capacities are not required to follow a particular growth strategy, the result is backed by a `SplitVec` with [`Recursive`] growth.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_linear_growth(2);
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let mapped = vec.map_preserving_layout(|x| x.to_string());
+    /// assert_eq!(&mapped, ["0", "1", "2", "3", "4", "5"].map(|x| x.to_string()).as_slice());
+    ///
+    /// let lengths: Vec<_> = mapped.fragments().iter().map(|x| x.len()).collect();
+    /// assert_eq!(lengths, [4, 2]);
+    /// ```
+    pub fn map_preserving_layout<U, F>(&self, mut f: F) -> ImpVec<U, SplitVec<U, Recursive>>
+    where
+        F: FnMut(&T) -> U,
+    {
+        let mut fragments = self.fragments().iter().map(|fragment| {
+            let mut mapped = Vec::with_capacity(fragment.capacity());
+            mapped.extend(fragment.iter().map(&mut f));
+            mapped
+        });
+
+        match fragments.next() {
+            None => ImpVec::with_recursive_growth(),
+            Some(first) => {
+                let mut split_vec: SplitVec<U, Recursive> = first.into();
+                split_vec.append(fragments.collect::<Vec<_>>());
+                split_vec.into()
+            }
+        }
+    }
+
+    /// Returns a diagnostic report describing the layout of the fragments of the underlying split vector,
+    /// listing the capacity and length of each fragment in order.
+    ///
+    /// This is helpful in understanding the memory layout or in reporting issues precisely.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_doubling_growth();
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(
+    ///     vec.layout_report(),
+    ///     "2 fragments: [cap=4 len=4][cap=8 len=3]"
+    /// );
+    /// ```
+    pub fn layout_report(&self) -> String {
+        let fragments = self.fragments();
+        let mut report = match fragments.len() {
+            1 => String::from("1 fragment: "),
+            n => alloc::format!("{} fragments: ", n),
+        };
+        for fragment in fragments {
+            _ = write!(
+                report,
+                "[cap={} len={}]",
+                fragment.capacity(),
+                fragment.len()
+            );
+        }
+        report.trim_end().into()
+    }
+}
+
+/// A one-shot override of the capacity of the next fragment to be allocated by the pinned vector `P`;
+/// see [`ImpVec::set_next_fragment_capacity`].
+///
+/// It is only created for split vectors with [`Recursive`] growth, which is the only strategy allowing fragments of arbitrary capacities.
+/// The functions are kept as pointers so that the generic growth path of the imp-vec can apply the override.
+pub(crate) struct NextFragment<P> {
+    capacity: usize,
+    room: fn(&P) -> usize,
+    allocate: fn(&mut P, usize),
+}
+
+impl<P> Clone for NextFragment<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for NextFragment<P> {}
+
+impl<P> NextFragment<P> {
+    /// Returns the number of elements that can be pushed before the fragment with the overridden capacity is allocated.
+    pub(crate) fn room(&self, pinned: &P) -> usize {
+        (self.room)(pinned)
+    }
+
+    /// Appends a new empty fragment with the overridden capacity to the pinned vector.
+    pub(crate) fn allocate(&self, pinned: &mut P) {
+        (self.allocate)(pinned, self.capacity)
+    }
+}
+
+impl<T> NextFragment<SplitVec<T, Recursive>> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            room: last_fragment_room,
+            allocate: allocate_fragment,
+        }
+    }
+}
+
+/// Room of the last fragment; an empty last fragment has no room since it is replaced rather than filled.
+fn last_fragment_room<T>(pinned: &SplitVec<T, Recursive>) -> usize {
+    match pinned.fragments().last() {
+        Some(fragment) if !fragment.is_empty() => fragment.room(),
+        _ => 0,
+    }
+}
+
+fn allocate_fragment<T>(pinned: &mut SplitVec<T, Recursive>, capacity: usize) {
+    // SAFETY: only an empty last fragment is removed; this does not affect any of the elements
+    let fragments = unsafe { pinned.fragments_mut() };
+    if fragments.last().is_some_and(|f| f.is_empty()) {
+        _ = fragments.pop();
+    }
+    pinned.append(Vec::<T>::with_capacity(capacity));
+}
+
+impl<T> ImpVec<T, SplitVec<T, Recursive>> {
+    /// Sets the capacity of the next fragment to be allocated by the underlying split vector,
+    /// overriding the capacity that would be computed by the growth strategy.
+    ///
+    /// The override is one-shot and lazy:
+    /// * the current last fragment is filled up first, as usual;
+    /// * once it is full, the next fragment is allocated with exactly the given `capacity`;
+    /// * once that fragment is full, the following fragments are allocated by the [`Recursive`] growth strategy again;
+    ///   i.e., each new fragment doubles the capacity of the prior one.
+    ///
+    /// If the last fragment is empty, it is replaced by the new fragment on the next push.
+    /// Calling the method again before the override is applied replaces the pending capacity.
+    /// Positions of the already pushed elements are not affected.
+    ///
+    /// The method is only available for the [`Recursive`] growth, since it is the only growth strategy
+    /// which allows fragments of arbitrary capacities.
+    /// The [`Doubling`] and [`Linear`] strategies locate the elements by the fragment capacities they dictate.
+    ///
+    /// [`Doubling`]: orx_split_vec::Doubling
+    /// [`Linear`]: orx_split_vec::Linear
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `capacity` is zero, or if the vector is [`seal`]ed.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_recursive_growth();
+    /// vec.imp_extend_from_slice(&[0, 1, 2]);
+    /// assert_eq!(vec.layout_report(), "1 fragment: [cap=4 len=3]");
+    ///
+    /// vec.set_next_fragment_capacity(100);
+    /// vec.imp_extend_from_slice(&[3, 4, 5]);
+    /// assert_eq!(vec.layout_report(), "2 fragments: [cap=4 len=4][cap=100 len=2]");
+    /// ```
+    pub fn set_next_fragment_capacity(&self, capacity: usize) {
+        assert!(capacity > 0, "fragment capacity must be positive");
+        assert!(
+            !self.is_sealed(),
+            "cannot push to a sealed imp-vec; see `ImpVec::seal`"
+        );
+        self.next_fragment.set(Some(NextFragment::new(capacity)));
+    }
+
+    /// Appends the `fragment` to the end of the underlying split vector as a new fragment, with a shared reference.
+    ///
+    /// The elements of the `fragment` are not copied; its buffer becomes a fragment of the split vector.
+    /// Existing fragments are not affected; hence, references to the already pushed elements remain valid.
+    ///
+    /// Note that the capacity of the grafted fragment does not conform to the growth strategy.
+    /// The subsequent pushes first fill the spare capacity of the grafted fragment, if any,
+    /// and the next fragment is allocated by the [`Recursive`] growth strategy with a capacity doubling the capacity of the grafted fragment.
+    /// If the current last fragment still has room, its remaining capacity is left unused.
+    ///
+    /// Nothing happens if the `fragment` is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is [`seal`]ed and the `fragment` is not empty.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_recursive_growth();
+    /// vec.imp_extend_from_slice(&[0, 1]);
+    /// let first = &vec[0];
+    ///
+    /// let fragment = vec![2, 3, 4, 5, 6];
+    /// let ptr = fragment.as_ptr();
+    /// vec.imp_push_fragment(fragment);
+    ///
+    /// assert_eq!(&vec, [0, 1, 2, 3, 4, 5, 6].as_slice());
+    /// assert_eq!(&vec[2] as *const i32, ptr); // not copied
+    /// assert_eq!(first, &0);
+    /// ```
+    pub fn imp_push_fragment(&self, fragment: Vec<T>) {
+        if fragment.is_empty() {
+            return;
+        }
+        assert!(
+            !self.is_sealed(),
+            "cannot push to a sealed imp-vec; see `ImpVec::seal`"
+        );
+
+        let pinned = self.pinned_mut();
+        // SAFETY: only an empty last fragment is removed; this does not affect any of the elements
+        let fragments = unsafe { pinned.fragments_mut() };
+        if fragments.last().is_some_and(|f| f.is_empty()) {
+            _ = fragments.pop();
+        }
+
+        pinned.append(fragment);
+    }
+
+    /// Appends all elements of the `iter` to the vector, with a shared reference,
+    /// reserving capacity for the lower bound of the size hint of the iterator up front.
+    ///
+    /// If the room left in the last fragment is less than the lower bound of the size hint,
+    /// the capacity of the next fragment is set to the missing amount by [`set_next_fragment_capacity`] prior to pushing the elements.
+    /// Therefore, iterators with an exact size hint fill up the last fragment and then are appended with at most one fragment allocation,
+    /// rather than a series of allocations of growing fragments.
+    ///
+    /// Similar to [`set_next_fragment_capacity`], the method is only available for the [`Recursive`] growth,
+    /// since the other growth strategies do not allow a fragment with the capacity of the missing amount.
+    ///
+    /// [`set_next_fragment_capacity`]: crate::ImpVec::set_next_fragment_capacity
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is [`seal`]ed and the `iter` yields at least one element.
+    /// The check precedes any allocation.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_recursive_growth();
+    /// vec.imp_extend_from_slice(&[0, 1, 2]);
+    ///
+    /// vec.imp_extend_reserving(3..1000);
+    /// assert_eq!(vec.len(), 1000);
+    /// assert_eq!(vec.layout_report(), "2 fragments: [cap=4 len=4][cap=996 len=996]");
+    /// ```
+    pub fn imp_extend_reserving<I: IntoIterator<Item = T>>(&self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        let room = last_fragment_room(self.pinned_mut());
+        if lower > room {
+            self.set_next_fragment_capacity(lower - room);
+        }
+        for x in iter {
+            self.imp_push(x);
+        }
+    }
+}
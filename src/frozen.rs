@@ -0,0 +1,105 @@
+use crate::ImpVec;
+use core::{marker::PhantomData, ops::Index};
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::SplitVec;
+
+/// A frozen, read-only counterpart of the [`ImpVec`] which is the final phase of a self-referential build.
+///
+/// A frozen vector can neither grow nor shrink, and its elements cannot be mutated or moved.
+/// The only available operations are [`len`](FrozenRefs::len), [`get`](FrozenRefs::get), [`iter`](FrozenRefs::iter) and indexing.
+/// Therefore, the address of every element stays the same for as long as the frozen vector lives;
+/// and any reference or pointer obtained while building the imp-vec keeps pointing to the same element.
+///
+/// An imp-vec is converted into a frozen vector by [`ImpVec::into_frozen_refs`].
+///
+/// # Example
+///
+/// ```rust
+/// use orx_imp_vec::*;
+///
+/// let vec = ImpVec::new();
+/// vec.imp_extend_from_slice(&['a', 'b', 'c']);
+/// let b: *const char = &vec[1];
+///
+/// let frozen = vec.into_frozen_refs();
+/// assert_eq!(frozen.len(), 3);
+/// assert_eq!(frozen.get(1), Some(&'b'));
+/// assert_eq!(&frozen[1] as *const char, b);
+/// assert_eq!(frozen.iter().collect::<String>(), "abc");
+/// ```
+///
+/// Frozen vectors do not allow any mutation:
+///
+/// ```compile_fail
+/// use orx_imp_vec::*;
+///
+/// let vec = ImpVec::new();
+/// vec.imp_push(42);
+///
+/// let mut frozen = vec.into_frozen_refs();
+/// frozen[0] = 7;
+/// ```
+pub struct FrozenRefs<T, P = SplitVec<T>>
+where
+    P: PinnedVec<T>,
+{
+    pinned_vec: P,
+    phantom: PhantomData<T>,
+}
+
+impl<T, P: PinnedVec<T>> FrozenRefs<T, P> {
+    /// Returns the number of elements in the frozen vector.
+    pub fn len(&self) -> usize {
+        self.pinned_vec.len()
+    }
+
+    /// Returns true if the frozen vector contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.pinned_vec.is_empty()
+    }
+
+    /// Returns a reference to the element at the `index`-th position; None if the index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.pinned_vec.get(index)
+    }
+
+    /// Returns an iterator over references to the elements of the frozen vector.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.pinned_vec.iter()
+    }
+}
+
+impl<T, P: PinnedVec<T>> Index<usize> for FrozenRefs<T, P> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.pinned_vec[index]
+    }
+}
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Converts the imp-vec into a [`FrozenRefs`] which only allows reading the elements.
+    ///
+    /// Since the frozen vector can never grow, shrink or mutate its elements, the address of every element is stable
+    /// for the entire lifetime of the frozen vector.
+    /// This makes it the safe endpoint of self-referential builds where elements hold pointers to each other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// let addr: *const i32 = vec.imp_push_get_ref(42);
+    /// vec.imp_push(7);
+    ///
+    /// let frozen = vec.into_frozen_refs();
+    /// assert_eq!(frozen.get(0).map(|x| x as *const i32), Some(addr));
+    /// ```
+    pub fn into_frozen_refs(self) -> FrozenRefs<T, P> {
+        FrozenRefs {
+            pinned_vec: self.into_inner(),
+            phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::ImpVec;
+use orx_pinned_vec::PinnedVec;
+use orx_split_vec::{Growth, SplitVec};
+
+/// Statistics on the fragments of a split vector backing an imp-vec; see [`ImpVec::growth_stats`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GrowthStats {
+    /// Number of fragments allocated.
+    pub fragment_count: usize,
+    /// Sum of the capacities of all fragments.
+    pub total_capacity: usize,
+    /// Number of elements in the vector.
+    pub total_len: usize,
+    /// Capacity of the largest fragment; zero if no fragment is allocated.
+    pub largest_fragment_capacity: usize,
+}
+
+impl<T, G: Growth> ImpVec<T, SplitVec<T, G>> {
+    /// Computes and returns statistics on the fragments of the underlying split vector.
+    ///
+    /// This is the pull-based complement of the [`on_grow`] callback:
+    /// rather than being notified on each allocation, the statistics can be polled at any time with a shared reference.
+    ///
+    /// [`on_grow`]: crate::ImpVec::on_grow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_doubling_growth();
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6]);
+    ///
+    /// let stats = vec.growth_stats();
+    /// assert_eq!(
+    ///     stats,
+    ///     GrowthStats {
+    ///         fragment_count: 2,
+    ///         total_capacity: 12,
+    ///         total_len: 7,
+    ///         largest_fragment_capacity: 8,
+    ///     }
+    /// );
+    /// ```
+    pub fn growth_stats(&self) -> GrowthStats {
+        let fragments = self.fragments();
+        GrowthStats {
+            fragment_count: fragments.len(),
+            total_capacity: self.capacity(),
+            total_len: self.len(),
+            largest_fragment_capacity: fragments.iter().map(|x| x.capacity()).max().unwrap_or(0),
+        }
+    }
+}
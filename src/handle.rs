@@ -0,0 +1,91 @@
+use crate::ImpVec;
+use core::{fmt::Debug, ops::Deref};
+use orx_pinned_vec::PinnedVec;
+
+/// A handle to an element of an [`ImpVec`], which is created by the [`ImpVec::push_handle`] method.
+///
+/// A handle dereferences to the element and is bound to the lifetime of the shared reference of the vector that it is created from.
+/// Therefore, it can never outlive the vector:
+///
+/// ```compile_fail
+/// use orx_imp_vec::*;
+///
+/// let handle = {
+///     let vec = ImpVec::new();
+///     vec.push_handle(42)
+/// };
+/// assert_eq!(*handle, 42);
+/// ```
+///
+/// On the other hand, since pushing to an imp-vec only requires a shared reference,
+/// handles remain valid while further elements are pushed to the vector.
+///
+/// ```rust
+/// use orx_imp_vec::*;
+///
+/// let vec = ImpVec::new();
+/// let a = vec.push_handle('a');
+/// let b = vec.push_handle('b');
+/// vec.imp_extend_from_slice(&['c'; 100]);
+///
+/// assert_eq!(*a, 'a');
+/// assert_eq!(*b, 'b');
+/// ```
+pub struct Handle<'a, T> {
+    element: &'a T,
+}
+
+impl<'a, T> Handle<'a, T> {
+    /// Returns the reference to the element with the lifetime of the vector borrow.
+    pub fn get(&self) -> &'a T {
+        self.element
+    }
+}
+
+impl<T> Clone for Handle<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Handle<'_, T> {}
+
+impl<T> Deref for Handle<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.element
+    }
+}
+
+impl<T: Debug> Debug for Handle<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Handle").field(self.element).finish()
+    }
+}
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Pushes the `value` to the vector and returns a [`Handle`] to it.
+    ///
+    /// The handle dereferences to the pushed element and it is bound to the lifetime of the `&self` borrow;
+    /// hence, it is guaranteed to remain valid while further elements are pushed to the vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    ///
+    /// let handle = vec.push_handle(String::from("x"));
+    /// vec.imp_push(String::from("y"));
+    ///
+    /// assert_eq!(handle.as_str(), "x");
+    /// assert_eq!(handle.get(), &vec[0]);
+    /// ```
+    pub fn push_handle(&self, value: T) -> Handle<'_, T> {
+        Handle {
+            element: self.imp_push_get_ref(value),
+        }
+    }
+}
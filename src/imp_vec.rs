@@ -1,7 +1,25 @@
-use core::{cell::UnsafeCell, marker::PhantomData};
-use orx_pinned_vec::PinnedVec;
+use crate::{fragments::NextFragment, ImpVecError};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    cell::{Cell, UnsafeCell},
+    marker::PhantomData,
+};
+use orx_fixed_vec::FixedVec;
+use orx_pinned_vec::{CapacityState, PinnedVec};
 use orx_split_vec::SplitVec;
 
+type GrowCallback = Box<dyn FnMut(usize) + Send>;
+
+/// The callback registered by `on_grow` together with the capacity of the vector that it was last notified of.
+///
+/// The capacity is cached so that the push path does not compute the capacity of the pinned vector,
+/// which requires visiting all fragments of a `SplitVec`.
+/// It is None when the capacity might have been changed other than by the push path, such as through `DerefMut`.
+pub(crate) struct GrowHook {
+    callback: GrowCallback,
+    capacity: Option<usize>,
+}
+
 /// `ImpVec`, stands for immutable push vector 👿, is a data structure which allows appending elements with a shared reference.
 ///
 /// Specifically, it extends vector capabilities with the following two methods:
@@ -69,6 +87,10 @@ where
 {
     pub(crate) pinned_vec: UnsafeCell<P>,
     pub(crate) phantom: PhantomData<T>,
+    pub(crate) on_grow: Cell<Option<GrowHook>>,
+    pub(crate) sealed: Cell<bool>,
+    pub(crate) next_fragment: Cell<Option<NextFragment<P>>>,
+    pub(crate) has_fixed_capacity: Cell<Option<bool>>,
 }
 
 impl<T, P: PinnedVec<T>> ImpVec<T, P> {
@@ -92,6 +114,157 @@ impl<T, P: PinnedVec<T>> ImpVec<T, P> {
         self.pinned_vec.into_inner()
     }
 
+    /// Consumes the imp-vec into a [`FixedVec`] with an exact capacity of `len`.
+    ///
+    /// This is useful to finalize an imp-vec which is built with a dynamic capacity,
+    /// such as a `SplitVec`, into a contiguous fixed vector with no unused capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let imp_vec = ImpVec::with_linear_growth(2);
+    /// imp_vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(imp_vec.fragments().len(), 2);
+    ///
+    /// let fixed_vec = imp_vec.into_fixed();
+    /// assert_eq!(fixed_vec.capacity(), 6);
+    /// assert_eq!(fixed_vec.as_slice(), &[0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn into_fixed(self) -> FixedVec<T> {
+        let pinned_vec = self.into_inner();
+        let mut fixed_vec = FixedVec::new(pinned_vec.len());
+        for x in pinned_vec {
+            fixed_vec.push(x);
+        }
+        fixed_vec
+    }
+
+    /// Clones the imp-vec into a new imp-vec backed by a [`FixedVec`] with an exact capacity of `len`.
+    ///
+    /// Unlike [`into_fixed`], this method does not consume the vector;
+    /// the clone is independent of the original vector which remains usable.
+    ///
+    /// [`into_fixed`]: crate::ImpVec::into_fixed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut imp_vec = ImpVec::with_linear_growth(2);
+    /// imp_vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let fixed = imp_vec.clone_into_fixed();
+    /// assert_eq!(fixed.capacity(), 6);
+    /// assert_eq!(&fixed, [0, 1, 2, 3, 4, 5].as_slice());
+    ///
+    /// imp_vec[0] = 42;
+    /// imp_vec.imp_push(6);
+    /// assert_eq!(&fixed, [0, 1, 2, 3, 4, 5].as_slice());
+    /// ```
+    pub fn clone_into_fixed(&self) -> ImpVec<T, FixedVec<T>>
+    where
+        T: Clone,
+    {
+        let mut fixed_vec = FixedVec::new(self.len());
+        for slice in self.slices(..) {
+            fixed_vec.extend_from_slice(slice);
+        }
+        fixed_vec.into()
+    }
+
+    /// Consumes the imp-vec into a standard `Vec` with a contiguous memory layout,
+    /// avoiding copies whenever possible.
+    ///
+    /// * If the underlying pinned vector is already contiguous, such as a `FixedVec` or a `SplitVec` with a single fragment,
+    ///   its buffer is moved into the resulting `Vec` without copying the elements.
+    /// * Otherwise, the elements are moved into a newly allocated `Vec`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let imp_vec = ImpVec::new();
+    /// imp_vec.imp_extend_from_slice(&[0, 1, 2]);
+    /// let ptr = imp_vec.first_ptr().unwrap();
+    ///
+    /// let vec = imp_vec.into_vec_maybe_zero_copy();
+    /// assert_eq!(vec, &[0, 1, 2]);
+    /// assert_eq!(vec.as_ptr(), ptr); // same buffer
+    ///
+    /// let imp_vec = ImpVec::new();
+    /// imp_vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(imp_vec.fragments().len(), 2);
+    ///
+    /// let vec = imp_vec.into_vec_maybe_zero_copy();
+    /// assert_eq!(vec, &[0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn into_vec_maybe_zero_copy(self) -> Vec<T>
+    where
+        P: Into<Vec<T>>,
+    {
+        self.into_inner().into()
+    }
+
+    /// Consumes the imp-vec and distributes its elements into two vectors: the elements for which `pred` returns true,
+    /// and the elements for which it returns false, respectively.
+    ///
+    /// The relative order of the elements is preserved in both vectors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let imp_vec = ImpVec::new();
+    /// imp_vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    ///
+    /// let (evens, odds) = imp_vec.into_partition(|x| x % 2 == 0);
+    /// assert_eq!(evens, [0, 2, 4]);
+    /// assert_eq!(odds, [1, 3, 5]);
+    /// ```
+    pub fn into_partition<F>(self, pred: F) -> (Vec<T>, Vec<T>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.into_iter().partition(pred)
+    }
+
+    /// Leaks the imp-vec and returns `'static` references to all of its elements.
+    ///
+    /// The vector is intentionally never dropped: its allocations are kept alive until the end of the program.
+    /// This is useful for arena-style usage where elements are required to live throughout the program.
+    /// Due to the pinned element guarantees, the returned references point to the original memory locations of the elements.
+    ///
+    /// Note that the elements are never dropped either; hence, the memory is reclaimed only by the operating system when the program exits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// fn create() -> Vec<&'static String> {
+    ///     let vec = ImpVec::new();
+    ///     vec.imp_push(String::from("a"));
+    ///     vec.imp_push(String::from("b"));
+    ///     vec.leak_refs()
+    /// }
+    ///
+    /// let refs = create();
+    /// assert_eq!(refs, [&String::from("a"), &String::from("b")]);
+    /// ```
+    pub fn leak_refs(self) -> Vec<&'static T>
+    where
+        T: 'static,
+        P: 'static,
+    {
+        let leaked: &'static Self = Box::leak(Box::new(self));
+        leaked.iter().collect()
+    }
+
     /// Pushes the `value` to the vector.
     /// This method differs from the `push` method with the required reference.
     /// Unlike `push`, `imp_push` allows to push the element with a shared reference.
@@ -167,8 +340,14 @@ impl<T, P: PinnedVec<T>> ImpVec<T, P> {
     /// In other words, when we do not rely on reduction methods, such as `count` or `sum`, appending element or elements to the end of the vector:
     /// * does not mutate any of already added elements, and hence,
     /// * **it is not different than creating a new element in the scope**.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is [`seal`]ed.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
     pub fn imp_push(&self, value: T) {
-        self.pinned_mut().push(value);
+        self.grow(|pinned| self.push_to(pinned, value));
     }
 
     /// Pushes the `value` to the vector and returns a reference to it.
@@ -196,11 +375,233 @@ impl<T, P: PinnedVec<T>> ImpVec<T, P> {
     /// assert_eq!(b, &'b');
     /// ```
     pub fn imp_push_get_ref(&self, value: T) -> &T {
+        self.grow(|pinned| self.push_to(pinned, value));
         let pinned = self.pinned_mut();
-        pinned.push(value);
         &pinned[pinned.len() - 1]
     }
 
+    /// Pushes the `value` to the vector and returns its index together with a reference to it.
+    ///
+    /// This is convenient when building collections which refer to the elements both by references and by indices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_push('a');
+    ///
+    /// let (idx, b) = vec.imp_push_get_ref_and_index('b');
+    /// assert_eq!(idx, 1);
+    /// assert_eq!(b, &'b');
+    /// assert!(core::ptr::eq(b, &vec[idx]));
+    /// ```
+    pub fn imp_push_get_ref_and_index(&self, value: T) -> (usize, &T) {
+        let idx = self.grow(|pinned| {
+            self.push_to(pinned, value);
+            pinned.len() - 1
+        });
+        (idx, &self.pinned_mut()[idx])
+    }
+
+    /// Pushes all elements of the array `arr` to the vector in order, with a shared reference.
+    ///
+    /// The elements are moved into the vector; hence, `T` is not required to be `Clone`.
+    /// See [`imp_push_array_get_refs`] to additionally obtain references to the pushed elements.
+    ///
+    /// [`imp_push_array_get_refs`]: crate::ImpVec::imp_push_array_get_refs
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// let first = vec.imp_push_get_ref(String::from("a"));
+    ///
+    /// vec.imp_push_array([String::from("b"), String::from("c")]);
+    ///
+    /// assert_eq!(first, "a");
+    /// assert_eq!(&vec, ["a", "b", "c"].map(String::from).as_slice());
+    /// ```
+    pub fn imp_push_array<const N: usize>(&self, arr: [T; N]) {
+        for x in arr {
+            self.imp_push(x);
+        }
+    }
+
+    /// Pushes all elements of the array `arr` to the vector in order, with a shared reference,
+    /// and returns references to the pushed elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_push(0);
+    ///
+    /// let [a, b, c] = vec.imp_push_array_get_refs([1, 2, 3]);
+    /// vec.imp_push(4);
+    ///
+    /// assert_eq!([a, b, c], [&1, &2, &3]);
+    /// assert_eq!(&vec, [0, 1, 2, 3, 4].as_slice());
+    /// ```
+    pub fn imp_push_array_get_refs<const N: usize>(&self, arr: [T; N]) -> [&T; N] {
+        let begin = self.len();
+        self.imp_push_array(arr);
+        let pinned: &P = self.pinned_mut();
+        core::array::from_fn(|i| &pinned[begin + i])
+    }
+
+    /// Extends the vector with the elements of the array `arr` in order, with a shared reference,
+    /// and returns references to all `N` appended elements.
+    ///
+    /// This is equivalent to [`imp_push_array_get_refs`]; it reads naturally alongside the other `imp_extend` methods.
+    /// Since the elements are pinned, all returned references remain valid while the vector keeps growing.
+    ///
+    /// [`imp_push_array_get_refs`]: crate::ImpVec::imp_push_array_get_refs
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    ///
+    /// let [a, b] = vec.imp_extend_array_get_refs(['a', 'b']);
+    /// vec.imp_extend_from_slice(&['c', 'd']);
+    ///
+    /// assert_eq!([a, b], [&'a', &'b']);
+    /// ```
+    pub fn imp_extend_array_get_refs<const N: usize>(&self, arr: [T; N]) -> [&T; N] {
+        self.imp_push_array_get_refs(arr)
+    }
+
+    /// Tries to push the `value` to the vector and returns a reference to it; returns back the `value` as the error when:
+    /// * the underlying pinned vector has a fixed capacity, such as a `FixedVec`, which is full, or
+    /// * the vector is [`seal`]ed.
+    ///
+    /// This is the non-panicking counterpart of [`imp_push_get_ref`].
+    /// When the underlying pinned vector has a dynamic capacity, such as a `SplitVec`, pushing to an unsealed vector always succeeds.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
+    /// [`imp_push_get_ref`]: crate::ImpVec::imp_push_get_ref
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_fixed_capacity(2);
+    ///
+    /// assert_eq!(vec.try_push_get_ref('a'), Ok(&'a'));
+    /// assert_eq!(vec.try_push_get_ref('b'), Ok(&'b'));
+    /// assert_eq!(vec.try_push_get_ref('c'), Err('c'));
+    ///
+    /// assert_eq!(&vec, ['a', 'b'].as_slice());
+    /// ```
+    pub fn try_push_get_ref(&self, value: T) -> Result<&T, T> {
+        match self.can_push(1) {
+            Err(_) => Err(value),
+            Ok(()) => Ok(self.imp_push_get_ref(value)),
+        }
+    }
+
+    /// Tries to push the `value` to the vector; returns an error without pushing the `value` when:
+    /// * the underlying pinned vector has a fixed capacity, such as a `FixedVec`, which is full, in which case
+    ///   [`ImpVecError::CapacityExceeded`] is returned, or
+    /// * the vector is [`seal`]ed, in which case [`ImpVecError::Sealed`] is returned.
+    ///
+    /// This is the non-panicking counterpart of [`imp_push`].
+    ///
+    /// [`seal`]: crate::ImpVec::seal
+    /// [`imp_push`]: crate::ImpVec::imp_push
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_fixed_capacity(1);
+    ///
+    /// assert_eq!(vec.try_imp_push('a'), Ok(()));
+    /// assert_eq!(
+    ///     vec.try_imp_push('b'),
+    ///     Err(ImpVecError::CapacityExceeded { capacity: 1, needed: 2 })
+    /// );
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.seal();
+    /// assert_eq!(vec.try_imp_push('a'), Err(ImpVecError::Sealed));
+    /// ```
+    pub fn try_imp_push(&self, value: T) -> Result<(), ImpVecError> {
+        self.can_push(1).map(|()| self.imp_push(value))
+    }
+
+    /// Checks whether `additional` elements can be pushed to the vector; returns the reason as the error if they cannot.
+    pub(crate) fn can_push(&self, additional: usize) -> Result<(), ImpVecError> {
+        if self.is_sealed() {
+            return Err(ImpVecError::Sealed);
+        }
+        match self.fixed_capacity() {
+            Some(capacity) if self.len() + additional > capacity => {
+                Err(ImpVecError::CapacityExceeded {
+                    capacity,
+                    needed: self.len() + additional,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the capacity of the underlying pinned vector if it is fixed, such as the capacity of a `FixedVec`; None if it is dynamic.
+    ///
+    /// Whether or not the capacity is fixed is determined once by `capacity_state`, which visits all fragments of a `SplitVec`;
+    /// afterwards, only the vectors with a fixed capacity are asked for their capacity.
+    fn fixed_capacity(&self) -> Option<usize> {
+        let pinned = self.pinned_mut();
+        let is_fixed = match self.has_fixed_capacity.get() {
+            Some(is_fixed) => is_fixed,
+            None => {
+                let is_fixed = matches!(pinned.capacity_state(), CapacityState::FixedCapacity(_));
+                self.has_fixed_capacity.set(Some(is_fixed));
+                is_fixed
+            }
+        };
+        is_fixed.then(|| pinned.capacity())
+    }
+
+    /// Pushes the `value` to the vector only if the condition `cond` evaluated on the current last element returns true;
+    /// returns whether or not the `value` is pushed.
+    ///
+    /// `cond` receives `None` when the vector is empty.
+    ///
+    /// Similar to `imp_push`, this method can be called with a shared reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// let is_increasing = |value: i32| move |last: Option<&i32>| last.map(|l| *l < value).unwrap_or(true);
+    ///
+    /// assert!(vec.imp_push_if(3, is_increasing(3)));
+    /// assert!(vec.imp_push_if(5, is_increasing(5)));
+    /// assert!(!vec.imp_push_if(4, is_increasing(4)));
+    ///
+    /// assert_eq!(&vec, [3, 5].as_slice());
+    /// ```
+    pub fn imp_push_if<F: FnOnce(Option<&T>) -> bool>(&self, value: T, cond: F) -> bool {
+        let push = cond(self.last());
+        if push {
+            self.imp_push(value);
+        }
+        push
+    }
+
     /// Extends the vector with the given `slice`.
     /// This method differs from the `extend_from_slice` method with the required reference.
     /// Unlike `extend_from_slice`, `imp_extend_from_slice` allows to push the element with a shared reference.
@@ -276,14 +677,355 @@ impl<T, P: PinnedVec<T>> ImpVec<T, P> {
     /// In other words, when we do not rely on reduction methods, such as `count` or `sum`, appending element or elements to the end of the vector:
     /// * does not mutate any of already added elements, and hence,
     /// * **it is not different than creating a new element in the scope**.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is [`seal`]ed.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
     pub fn imp_extend_from_slice(&self, slice: &[T])
     where
         T: Clone,
     {
-        self.pinned_mut().extend_from_slice(slice);
+        self.grow(|pinned| self.extend_to(pinned, slice));
+    }
+
+    /// Pads the vector with clones of the `value` until its length reaches `target_len`, with a shared reference.
+    ///
+    /// Does nothing if the length of the vector is already greater than or equal to `target_len`.
+    /// Otherwise, `target_len - len` elements are appended; the last of which is the `value` itself, and the others are its clones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is [`seal`]ed and at least one element needs to be appended;
+    /// or if the underlying pinned vector has a fixed capacity which is less than `target_len`.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['a', 'b']);
+    ///
+    /// vec.imp_pad_to(5, '_');
+    /// assert_eq!(&vec, ['a', 'b', '_', '_', '_'].as_slice());
+    ///
+    /// vec.imp_pad_to(3, '*');
+    /// assert_eq!(vec.len(), 5);
+    /// ```
+    pub fn imp_pad_to(&self, target_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let additional = target_len.saturating_sub(self.len());
+        if additional > 0 {
+            for _ in 1..additional {
+                self.imp_push(value.clone());
+            }
+            self.imp_push(value);
+        }
+    }
+
+    /// Extends the vector with as many elements of the given `slice` as fit into the capacity of the vector,
+    /// and returns the number of elements actually appended.
+    ///
+    /// * When the underlying pinned vector has a fixed capacity, such as a `FixedVec`, only the prefix of the `slice`
+    ///   that fits into the remaining room is appended; and hence, this method never panics due to "out-of-capacity".
+    /// * When the underlying pinned vector has a dynamic capacity, such as a `SplitVec`, all elements are appended.
+    ///
+    /// Similar to `imp_extend_from_slice`, this method can be called with a shared reference.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_fixed_capacity(5);
+    /// vec.imp_extend_from_slice(&[0, 1, 2]);
+    ///
+    /// let num_appended = vec.imp_extend_up_to_capacity(&[3, 4, 5, 6]);
+    /// assert_eq!(num_appended, 2);
+    /// assert_eq!(&vec, [0, 1, 2, 3, 4].as_slice());
+    ///
+    /// let num_appended = vec.imp_extend_up_to_capacity(&[7]);
+    /// assert_eq!(num_appended, 0);
+    /// ```
+    pub fn imp_extend_up_to_capacity(&self, slice: &[T]) -> usize
+    where
+        T: Clone,
+    {
+        let num_fit = match self.fixed_capacity() {
+            Some(capacity) => slice.len().min(capacity.saturating_sub(self.len())),
+            None => slice.len(),
+        };
+        self.grow(|pinned| self.extend_to(pinned, &slice[..num_fit]));
+        num_fit
+    }
+
+    /// Extends the vector with all elements yielded by the `iter`, with a shared reference,
+    /// and returns the number of elements appended.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is [`seal`]ed and the `iter` yields at least one element;
+    /// or if the underlying pinned vector has a fixed capacity which is exceeded.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_push(0);
+    ///
+    /// let num_appended = vec.imp_extend_count((1..10).filter(|x| x % 3 == 0));
+    /// assert_eq!(num_appended, 3);
+    /// assert_eq!(&vec, [0, 3, 6, 9].as_slice());
+    /// ```
+    pub fn imp_extend_count<I: IntoIterator<Item = T>>(&self, iter: I) -> usize {
+        let mut count = 0;
+        for value in iter {
+            self.imp_push(value);
+            count += 1;
+        }
+        count
+    }
+
+    /// Extends the vector by cloning all elements of the `other` imp-vec, with a shared reference.
+    ///
+    /// Neither of the vectors is consumed; both remain usable afterwards.
+    /// Note that the vectors are not required to have the same underlying pinned vector type.
+    /// Further, extending a vector with itself is allowed, in which case the elements are cloned once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1]);
+    ///
+    /// let other = ImpVec::with_fixed_capacity(4);
+    /// other.imp_extend_from_slice(&[2, 3]);
+    ///
+    /// vec.imp_extend_from_impvec(&other);
+    /// assert_eq!(&vec, [0, 1, 2, 3].as_slice());
+    /// assert_eq!(&other, [2, 3].as_slice());
+    ///
+    /// vec.imp_extend_from_impvec(&vec);
+    /// assert_eq!(&vec, [0, 1, 2, 3, 0, 1, 2, 3].as_slice());
+    /// ```
+    pub fn imp_extend_from_impvec<P2: PinnedVec<T>>(&self, other: &ImpVec<T, P2>)
+    where
+        T: Clone,
+    {
+        match core::ptr::eq(
+            self.pinned_vec.get() as *const (),
+            other.pinned_vec.get() as *const (),
+        ) {
+            // self extension: elements must be read by index since the vector grows while being traversed
+            true => {
+                for i in 0..other.len() {
+                    let value = other[i].clone();
+                    self.imp_push(value);
+                }
+            }
+            false => {
+                for slice in other.slices(..) {
+                    self.imp_extend_from_slice(slice);
+                }
+            }
+        }
+    }
+
+    /// Pushes the `value` to the vector.
+    ///
+    /// This method shadows the `push` method of the underlying pinned vector
+    /// so that the callback registered by [`on_grow`] is notified when the vector grows.
+    ///
+    /// [`on_grow`]: crate::ImpVec::on_grow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec = ImpVec::new();
+    /// vec.push(42);
+    /// assert_eq!(&vec, [42].as_slice());
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.imp_push(value);
+    }
+
+    /// Registers the `callback` to be called whenever the vector grows by allocating additional capacity,
+    /// such as a new fragment of a `SplitVec`.
+    ///
+    /// The callback receives the new total capacity of the vector.
+    /// It is called by the growth path of the push methods of the imp-vec: `push`, `imp_push`, `imp_push_get_ref`,
+    /// `imp_extend_from_slice` and methods built on top of them.
+    /// When a single extend call allocates multiple fragments, the callback is called once with the final capacity.
+    ///
+    /// Registering a new callback replaces the prior one; clones of the vector do not inherit the callback.
+    /// The callback is required to be `Send` so that the imp-vec remains `Send` whenever its elements are.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let capacities = Arc::new(Mutex::new(vec![]));
+    /// let log = capacities.clone();
+    ///
+    /// let mut vec = ImpVec::with_linear_growth(2);
+    /// vec.on_grow(Box::new(move |capacity| log.lock().unwrap().push(capacity)));
+    ///
+    /// for i in 0..9 {
+    ///     vec.imp_push(i);
+    /// }
+    /// assert_eq!(capacities.lock().unwrap().as_slice(), &[8, 12]);
+    /// ```
+    pub fn on_grow(&mut self, callback: Box<dyn FnMut(usize) + Send>) {
+        self.on_grow.set(Some(GrowHook {
+            callback,
+            capacity: None,
+        }));
+    }
+
+    /// Seals the vector so that it is finalized and no further elements can be pushed to it.
+    ///
+    /// This is useful to prevent accidental pushes once the vector is built, without converting it into a different type.
+    /// After the vector is sealed:
+    /// * all read and in-place mutation methods keep working as before,
+    /// * the push and extend methods defined on the imp-vec itself, which are `push` and the `imp_*` methods
+    ///   such as `imp_push` or `imp_extend_from_slice`, panic,
+    /// * the fallible counterparts, such as `try_imp_push` or `try_push_get_ref`, return an error instead.
+    ///
+    /// Note that sealing is a guard on the shared-reference growth of the imp-vec.
+    /// Methods of the underlying pinned vector which are reachable through a mutable reference by `DerefMut`,
+    /// such as `PinnedVec::push`, `extend_from_slice` or `insert`, are not affected.
+    ///
+    /// Sealing is permanent; a sealed vector cannot be unsealed.
+    /// However, consuming the vector, for instance by `into_inner`, gives back the underlying pinned vector which can grow.
+    ///
+    /// # Example
+    ///
+    /// ```rust should_panic
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1, 2]);
+    ///
+    /// vec.seal();
+    /// assert!(vec.is_sealed());
+    /// assert_eq!(&vec, [0, 1, 2].as_slice());
+    ///
+    /// vec.imp_push(3); // panics!
+    /// ```
+    pub fn seal(&self) {
+        self.sealed.set(true);
+    }
+
+    /// Returns whether or not the vector is [`seal`]ed; in which case, no further elements can be pushed to it.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_push(42);
+    /// assert!(!vec.is_sealed());
+    ///
+    /// vec.seal();
+    /// assert!(vec.is_sealed());
+    /// ```
+    pub fn is_sealed(&self) -> bool {
+        self.sealed.get()
+    }
+
+    // helpers
+    fn grow<R>(&self, push: impl FnOnce(&mut P) -> R) -> R {
+        assert!(
+            !self.is_sealed(),
+            "cannot push to a sealed imp-vec; see `ImpVec::seal`"
+        );
+        let pinned = self.pinned_mut();
+        let capacity = match self.on_grow.take() {
+            None => return push(pinned),
+            Some(mut hook) => {
+                let capacity = hook.capacity.unwrap_or_else(|| pinned.capacity());
+                hook.capacity = Some(capacity);
+                self.on_grow.set(Some(hook));
+                capacity
+            }
+        };
+
+        let allocates_next_fragment = self.next_fragment.get().is_some();
+        let result = push(pinned);
+        let allocated = pinned.len() > capacity
+            || (allocates_next_fragment && self.next_fragment.get().is_none());
+        if allocated {
+            if let Some(mut hook) = self.on_grow.take() {
+                let new_capacity = pinned.capacity();
+                hook.capacity = Some(new_capacity);
+                if new_capacity > capacity {
+                    (hook.callback)(new_capacity);
+                }
+                self.on_grow.set(Some(hook));
+            }
+        }
+        result
+    }
+
+    /// Clears the capacity cached for the `on_grow` callback, which is required whenever the capacity
+    /// of the pinned vector might change other than by the push path.
+    pub(crate) fn forget_capacity(&self) {
+        if let Some(mut hook) = self.on_grow.take() {
+            hook.capacity = None;
+            self.on_grow.set(Some(hook));
+        }
+    }
+
+    /// Pushes the `value` to the `pinned` vector; allocating the pending next fragment first if there is no room left.
+    fn push_to(&self, pinned: &mut P, value: T) {
+        if let Some(next) = self.next_fragment.get() {
+            if next.room(pinned) == 0 {
+                next.allocate(pinned);
+                self.next_fragment.set(None);
+            }
+        }
+        pinned.push(value);
+    }
+
+    /// Extends the `pinned` vector by the `slice`; the elements which do not fit into the room left
+    /// are pushed into the pending next fragment, if any.
+    fn extend_to(&self, pinned: &mut P, slice: &[T])
+    where
+        T: Clone,
+    {
+        match self.next_fragment.get() {
+            Some(next) if !slice.is_empty() => {
+                let (head, tail) = slice.split_at(next.room(pinned).min(slice.len()));
+                pinned.extend_from_slice(head);
+                if !tail.is_empty() {
+                    next.allocate(pinned);
+                    self.next_fragment.set(None);
+                    pinned.extend_from_slice(tail);
+                }
+            }
+            _ => pinned.extend_from_slice(slice),
+        }
     }
 
-    // helper
     #[allow(clippy::mut_from_ref)]
     pub(crate) fn pinned_mut(&self) -> &mut P {
         // SAFETY: `ImpVec` does not implement Send or Sync.
@@ -318,6 +1060,10 @@ where
         Self {
             pinned_vec: pinned_vec.into(),
             phantom: self.phantom,
+            on_grow: Default::default(),
+            sealed: self.sealed.clone(),
+            next_fragment: Default::default(),
+            has_fixed_capacity: self.has_fixed_capacity.clone(),
         }
     }
 }
@@ -0,0 +1,116 @@
+use crate::ImpVec;
+use core::{cell::RefCell, hash::Hash};
+use orx_pinned_vec::PinnedVec;
+use std::collections::HashMap;
+
+/// An interner deduplicating values and assigning each distinct value a stable index.
+///
+/// Values are stored in an [`ImpVec`]; therefore, references obtained by [`resolve`] remain valid
+/// while new values are interned with a shared reference.
+/// A hash map from values to their indices provides the deduplication in amortized *O(1)* time.
+///
+/// [`resolve`]: crate::Interner::resolve
+///
+/// # Example
+///
+/// ```rust
+/// use orx_imp_vec::*;
+///
+/// let interner = ImpVec::interner();
+///
+/// let x = interner.intern("x");
+/// let y = interner.intern("y");
+/// assert_eq!(interner.intern("x"), x);
+///
+/// let ref_to_x = interner.resolve(x);
+/// _ = interner.intern("z");
+///
+/// assert_eq!(ref_to_x, &"x");
+/// assert_eq!(interner.resolve(y), &"y");
+/// assert_eq!(interner.len(), 3);
+/// ```
+pub struct Interner<T> {
+    values: ImpVec<T>,
+    indices: RefCell<HashMap<T, usize>>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Interner<T> {
+    /// Creates a new empty interner.
+    pub fn new() -> Self {
+        Self {
+            values: ImpVec::new(),
+            indices: Default::default(),
+        }
+    }
+
+    /// Returns the number of distinct values interned so far.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if no value is interned yet.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns a reference to the value with the given index which is previously returned by [`intern`].
+    ///
+    /// [`intern`]: crate::Interner::intern
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `index` is out of bounds.
+    pub fn resolve(&self, index: usize) -> &T {
+        &self.values[index]
+    }
+
+    /// Returns a reference to the underlying imp-vec of distinct values in the order they are interned.
+    pub fn values(&self) -> &ImpVec<T> {
+        &self.values
+    }
+}
+
+impl<T: Clone + Eq + Hash> Interner<T> {
+    /// Interns the `value` and returns its index:
+    /// * if an equal value is interned before, its index is returned,
+    /// * otherwise, the `value` is appended to the interned values and its new index is returned.
+    ///
+    /// Indices are stable; i.e., equal values are always mapped to the same index.
+    /// Interning a new value does not invalidate references obtained by `resolve`.
+    pub fn intern(&self, value: T) -> usize {
+        let mut indices = self.indices.borrow_mut();
+        match indices.get(&value) {
+            Some(index) => *index,
+            None => {
+                let index = self.values.len();
+                indices.insert(value.clone(), index);
+                self.values.imp_push(value);
+                index
+            }
+        }
+    }
+}
+
+impl<T> ImpVec<T> {
+    /// Creates a new empty [`Interner`] storing its distinct values in an imp-vec.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let interner = ImpVec::interner();
+    /// assert_eq!(interner.intern(String::from("a")), 0);
+    /// assert_eq!(interner.intern(String::from("b")), 1);
+    /// assert_eq!(interner.intern(String::from("a")), 0);
+    /// ```
+    pub fn interner() -> Interner<T> {
+        Interner::new()
+    }
+}
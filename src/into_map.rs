@@ -0,0 +1,34 @@
+use crate::ImpVec;
+use core::hash::Hash;
+use orx_pinned_vec::PinnedVec;
+use std::collections::HashMap;
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Consumes the vector and collects its elements into a hash map where each element is inserted
+    /// under the key computed by the `key` function.
+    ///
+    /// Elements are inserted in their order in the vector; therefore, when multiple elements have the same key,
+    /// the last one wins.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&["apple", "avocado", "banana"]);
+    ///
+    /// let map = vec.into_map(|x| x.chars().next());
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get(&Some('a')), Some(&"avocado"));
+    /// assert_eq!(map.get(&Some('b')), Some(&"banana"));
+    /// ```
+    pub fn into_map<K: Eq + Hash, F: Fn(&T) -> K>(self, key: F) -> HashMap<K, T> {
+        let mut map = HashMap::with_capacity(self.len());
+        for value in self {
+            map.insert(key(&value), value);
+        }
+        map
+    }
+}
@@ -0,0 +1,193 @@
+use crate::ImpVec;
+use alloc::{collections::VecDeque, vec::Vec};
+use core::iter::Peekable;
+use orx_pinned_vec::PinnedVec;
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Applies the fallible function `f` to each element of the vector in order,
+    /// stopping at the first error and returning it.
+    ///
+    /// Returns `Ok(())` if `f` succeeds for all elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[1, 2, 3, -4, 5]);
+    ///
+    /// let mut num_visited = 0;
+    /// let result = vec.try_for_each(|x| {
+    ///     num_visited += 1;
+    ///     match *x > 0 {
+    ///         true => Ok(()),
+    ///         false => Err(*x),
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(result, Err(-4));
+    /// assert_eq!(num_visited, 4);
+    /// ```
+    pub fn try_for_each<E, F>(&self, f: F) -> Result<(), E>
+    where
+        F: FnMut(&T) -> Result<(), E>,
+    {
+        self.iter().try_for_each(f)
+    }
+
+    /// Returns the sum of `f(x)` over all elements `x` of the vector, computed in a single pass.
+    ///
+    /// This is equivalent to `vec.iter().map(f).sum()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// struct Item {
+    ///     price: u32,
+    ///     quantity: u32,
+    /// }
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_push(Item { price: 3, quantity: 2 });
+    /// vec.imp_push(Item { price: 5, quantity: 1 });
+    ///
+    /// let total: u32 = vec.sum_by(|x| x.price * x.quantity);
+    /// assert_eq!(total, 11);
+    /// ```
+    pub fn sum_by<S, F>(&self, f: F) -> S
+    where
+        S: core::iter::Sum,
+        F: FnMut(&T) -> S,
+    {
+        self.iter().map(f).sum()
+    }
+
+    /// Groups consecutive elements sharing the same key into runs, and returns the runs in order.
+    ///
+    /// Each run is a collection of references to consecutive elements for which `key` returns equal values.
+    /// Note that elements with equal keys which are not adjacent belong to different runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&["a1", "a2", "b1", "a3", "a4", "a5"]);
+    ///
+    /// let runs = vec.group_consecutive_by(|x| x.chars().next());
+    /// assert_eq!(runs, [vec![&"a1", &"a2"], vec![&"b1"], vec![&"a3", &"a4", &"a5"]]);
+    /// ```
+    pub fn group_consecutive_by<K, F>(&self, mut key: F) -> Vec<Vec<&T>>
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        let mut runs: Vec<Vec<&T>> = Vec::new();
+        let mut last_key = None;
+        for x in self.iter() {
+            let k = key(x);
+            match (last_key.as_ref() == Some(&k), runs.last_mut()) {
+                (true, Some(run)) => run.push(x),
+                _ => runs.push(alloc::vec![x]),
+            }
+            last_key = Some(k);
+        }
+        runs
+    }
+
+    /// Returns an iterator over all contiguous windows of length `size` of the vector.
+    /// The windows overlap; each window is yielded as a vector of references to its elements.
+    ///
+    /// Windows crossing the fragment boundaries of the underlying pinned vector are handled transparently.
+    /// If the vector is shorter than `size`, the iterator yields no windows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero, similar to `slice::windows`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['a', 'b', 'c', 'd']);
+    ///
+    /// let windows: Vec<_> = vec.windows(3).collect();
+    /// assert_eq!(windows, [vec![&'a', &'b', &'c'], vec![&'b', &'c', &'d']]);
+    ///
+    /// assert_eq!(vec.windows(5).count(), 0);
+    /// ```
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = Vec<&T>> {
+        assert!(size != 0, "window size must be non-zero");
+        let mut window = VecDeque::with_capacity(size);
+        self.iter().filter_map(move |x| {
+            if window.len() == size {
+                _ = window.pop_front();
+            }
+            window.push_back(x);
+            (window.len() == size).then(|| window.iter().copied().collect())
+        })
+    }
+
+    /// Returns an iterator which threads the mutable `state`, initiated as `init`, through the elements of the vector in order,
+    /// yielding `f(&mut state, x)` for each element `x`.
+    ///
+    /// Unlike `Iterator::scan`, the iteration cannot be terminated early by `f`; the iterator yields exactly `len` values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[1, 2, 3, 4]);
+    ///
+    /// let running_sums: Vec<_> = vec
+    ///     .scan_state(0, |sum, x| {
+    ///         *sum += x;
+    ///         *sum
+    ///     })
+    ///     .collect();
+    /// assert_eq!(running_sums, [1, 3, 6, 10]);
+    /// ```
+    pub fn scan_state<'a, S, U, F>(&'a self, init: S, mut f: F) -> impl Iterator<Item = U> + 'a
+    where
+        S: Clone + 'a,
+        F: FnMut(&mut S, &T) -> U + 'a,
+    {
+        let mut state = init;
+        self.iter().map(move |x| f(&mut state, x))
+    }
+
+    /// Returns a peekable iterator over the elements of the vector,
+    /// which allows to look ahead at the next element without consuming it.
+    ///
+    /// This is convenient for parser-style consumers which decide how to proceed depending on the next element.
+    /// Peeking is cheap as the underlying iterator yields the elements fragment by fragment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['1', '2', '+', '3']);
+    ///
+    /// let mut iter = vec.iter_peekable();
+    /// let mut number = 0;
+    /// while let Some(digit) = iter.next_if(|x| x.is_ascii_digit()) {
+    ///     number = number * 10 + digit.to_digit(10).unwrap();
+    /// }
+    ///
+    /// assert_eq!(number, 12);
+    /// assert_eq!(iter.peek(), Some(&&'+'));
+    /// ```
+    pub fn iter_peekable(&self) -> Peekable<impl Iterator<Item = &T>> {
+        self.iter().peekable()
+    }
+}
@@ -13,12 +13,51 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+mod access;
+mod aligned;
+mod builder;
+mod cmp;
 mod common_traits;
+mod cyclic;
+mod edit;
+mod editable;
+mod errors;
+mod fingerprint;
+mod fixed;
+mod fragments;
+mod frozen;
+mod growth_stats;
+mod handle;
 mod imp_vec;
+#[cfg(feature = "std")]
+mod interner;
+#[cfg(feature = "std")]
+mod into_map;
+mod iter;
 mod new;
+mod node_id;
+#[cfg(feature = "std")]
+mod par;
+mod self_ref;
+mod sort;
 
+pub use aligned::AlignedVec;
+pub use builder::ImpVecBuilder;
+pub use cyclic::CyclicBuilder;
+pub use editable::EditableImpVec;
+pub use errors::{ImpVecError, IndexError};
+pub use fixed::FixedImpVec;
+pub use frozen::FrozenRefs;
+pub use growth_stats::GrowthStats;
+pub use handle::Handle;
 pub use imp_vec::ImpVec;
+#[cfg(feature = "std")]
+pub use interner::Interner;
+pub use node_id::NodeId;
+pub use orx_concurrent_iter::{ConcurrentIter, IntoConcurrentIter};
 pub use orx_fixed_vec::FixedVec;
-pub use orx_pinned_vec::PinnedVec;
-pub use orx_split_vec::{Doubling, Growth, Linear, Recursive, SplitVec};
+pub use orx_pinned_vec::{Collection, CollectionMut, PinnedVec};
+pub use orx_split_vec::{Doubling, Growth, Linear, Recursive, SplitVec, SplitVecSlice};
@@ -1,5 +1,7 @@
 use crate::ImpVec;
+use alloc::vec::Vec;
 use orx_fixed_vec::FixedVec;
+use orx_pinned_vec::PinnedVec;
 use orx_split_vec::{Doubling, Linear, Recursive, SplitVec};
 
 impl<T> ImpVec<T> {
@@ -19,8 +21,39 @@ impl<T> ImpVec<T> {
         Self {
             pinned_vec: SplitVec::default().into(),
             phantom: Default::default(),
+            on_grow: Default::default(),
+            sealed: Default::default(),
+            next_fragment: Default::default(),
+            has_fixed_capacity: Default::default(),
         }
     }
+
+    /// Creates a new imp-vec from the `Ok` values of the `iter`; or returns the first `Err` encountered.
+    ///
+    /// The iteration stops at the first error; the partially built vector is dropped and the error is returned.
+    /// This is the counterpart of collecting an iterator of results into `Result<Vec<T>, E>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::try_from_iter(["1", "2", "3"].map(|x| x.parse::<u32>())).unwrap();
+    /// assert_eq!(&vec, [1, 2, 3].as_slice());
+    ///
+    /// let result = ImpVec::try_from_iter(["1", "x", "3"].map(|x| x.parse::<u32>()));
+    /// assert!(result.is_err());
+    /// ```
+    pub fn try_from_iter<E, I>(iter: I) -> Result<Self, E>
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        let vec = Self::new();
+        for x in iter {
+            vec.imp_push(x?);
+        }
+        Ok(vec)
+    }
 }
 
 impl<T> ImpVec<T, SplitVec<T, Doubling>> {
@@ -28,6 +61,32 @@ impl<T> ImpVec<T, SplitVec<T, Doubling>> {
     pub fn with_doubling_growth() -> Self {
         SplitVec::with_doubling_growth().into()
     }
+
+    /// Creates a new ImpVec backed by a [`SplitVec<T, Doubling>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Doubling.html)
+    /// which contains `n` default values.
+    ///
+    /// This is convenient for grid or arena patterns where the slots are allocated up front and filled out of order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let mut vec: ImpVec<Option<char>, _> = ImpVec::with_defaults(4);
+    /// vec[2] = Some('x');
+    ///
+    /// assert_eq!(&vec, [None, None, Some('x'), None].as_slice());
+    /// ```
+    pub fn with_defaults(n: usize) -> Self
+    where
+        T: Default,
+    {
+        let vec = Self::with_doubling_growth();
+        for _ in 0..n {
+            vec.imp_push(T::default());
+        }
+        vec
+    }
 }
 
 impl<T> ImpVec<T, SplitVec<T, Recursive>> {
@@ -35,13 +94,70 @@ impl<T> ImpVec<T, SplitVec<T, Recursive>> {
     pub fn with_recursive_growth() -> Self {
         SplitVec::with_recursive_growth().into()
     }
+
+    /// Creates a new ImpVec from the given exact size iterator where all elements are collected into a single fragment
+    /// with a capacity equal to the length of the iterator.
+    ///
+    /// Since the length of the iterator is known up front, collection is completed with a single allocation.
+    ///
+    /// Note that a [`SplitVec<T, Doubling>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Doubling.html) requires its fragment capacities to follow the doubling sequence;
+    /// and hence, cannot hold an arbitrary sized single fragment.
+    /// This is why the resulting imp-vec is backed by a [`SplitVec<T, Recursive>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Recursive.html),
+    /// which is capable of holding fragments with arbitrary capacities.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::from_exact_size_iter(0..1000);
+    /// assert_eq!(vec.len(), 1000);
+    /// assert_eq!(vec.fragments().len(), 1);
+    /// assert_eq!(vec.capacity(), 1000);
+    /// ```
+    pub fn from_exact_size_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let iter = iter.into_iter();
+        match iter.len() {
+            0 => Self::with_recursive_growth(),
+            len => {
+                let mut vec = Vec::with_capacity(len);
+                vec.extend(iter);
+                SplitVec::from(vec).into()
+            }
+        }
+    }
 }
 
 impl<T> ImpVec<T, SplitVec<T, Linear>> {
     /// Creates a new ImpVec by creating and wrapping up a new [`SplitVec<T, Linear>`](https://docs.rs/orx-split-vec/latest/orx_split_vec/struct.Linear.html) as the underlying storage.
     ///
     /// * Each fragment of the underlying split vector will have a capacity of  `2 ^ constant_fragment_capacity_exponent`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fragment capacity `2 ^ constant_fragment_capacity_exponent` overflows `usize`.
+    ///
+    /// ```rust should_panic
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec: ImpVec<char, _> = ImpVec::with_linear_growth(100);
+    /// ```
     pub fn with_linear_growth(constant_fragment_capacity_exponent: usize) -> Self {
+        let fragment_capacity = u32::try_from(constant_fragment_capacity_exponent)
+            .ok()
+            .and_then(|exp| 2usize.checked_pow(exp));
+        assert!(
+            fragment_capacity.is_some(),
+            "constant_fragment_capacity_exponent {} is too large: the fragment capacity 2 ^ {} overflows usize::MAX = {}",
+            constant_fragment_capacity_exponent,
+            constant_fragment_capacity_exponent,
+            usize::MAX,
+        );
+
         SplitVec::with_linear_growth(constant_fragment_capacity_exponent).into()
     }
 }
@@ -60,4 +176,66 @@ impl<T> ImpVec<T, FixedVec<T>> {
     pub fn with_fixed_capacity(fixed_capacity: usize) -> Self {
         FixedVec::new(fixed_capacity).into()
     }
+
+    /// Creates a new ImpVec wrapping a [`FixedVec<T>`] with a capacity of exactly `n`.
+    ///
+    /// This is equivalent to [`with_fixed_capacity`]; it makes the intent explicit when the final length of the vector is known up front.
+    /// The vector can then be checked to be filled up by [`is_full`].
+    ///
+    /// [`with_fixed_capacity`]: crate::ImpVec::with_fixed_capacity
+    /// [`is_full`]: crate::ImpVec::is_full
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::with_exact_capacity(3);
+    /// vec.imp_extend_from_slice(&['a', 'b']);
+    /// assert!(!vec.is_full());
+    ///
+    /// vec.imp_push('c');
+    /// assert!(vec.is_full());
+    /// assert_eq!(vec.capacity(), 3);
+    /// ```
+    pub fn with_exact_capacity(n: usize) -> Self {
+        Self::with_fixed_capacity(n)
+    }
+
+    /// Returns true if the vector has reached its fixed capacity; i.e., if no more elements can be pushed.
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Collects the elements of the `iter` into a new ImpVec wrapping a [`FixedVec<T>`] with a capacity of exactly `N`;
+    /// returns None if the `iter` yields more than `N` elements.
+    ///
+    /// Note that the iterator might yield fewer than `N` elements, in which case the vector has room for the remaining ones.
+    /// Further, the iterator is consumed only up to the first `N + 1` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::collect_array::<4, _>(0..4).unwrap();
+    /// assert_eq!(&vec, [0, 1, 2, 3].as_slice());
+    /// assert_eq!(vec.capacity(), 4);
+    ///
+    /// let vec = ImpVec::collect_array::<4, _>(0..2).unwrap();
+    /// assert_eq!(&vec, [0, 1].as_slice());
+    /// assert_eq!(vec.capacity(), 4);
+    ///
+    /// assert!(ImpVec::collect_array::<4, _>(0..5).is_none());
+    /// ```
+    pub fn collect_array<const N: usize, I: IntoIterator<Item = T>>(iter: I) -> Option<Self> {
+        let mut fixed_vec = FixedVec::new(N);
+        for x in iter {
+            if fixed_vec.len() == N {
+                return None;
+            }
+            fixed_vec.push(x);
+        }
+        Some(fixed_vec.into())
+    }
 }
@@ -0,0 +1,35 @@
+/// A type-safe index of an element of an imp-vec.
+///
+/// It is a thin wrapper around a `usize` index; the storage of the vector is not affected.
+/// However, it helps to distinguish positions of elements from plain integers,
+/// which is particularly useful while building self-referential collections where elements refer to each other by their positions.
+///
+/// An imp-vec can be indexed by a `NodeId` as well as by a `usize`.
+///
+/// # Example
+///
+/// ```rust
+/// use orx_imp_vec::*;
+///
+/// let vec = ImpVec::new();
+/// vec.imp_extend_from_slice(&['a', 'b', 'c']);
+///
+/// let id = NodeId(1);
+/// assert_eq!(vec[id], 'b');
+/// assert_eq!(vec.get_node(id), Some(&'b'));
+/// assert_eq!(vec.get_node(NodeId(3)), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(pub usize);
+
+impl From<usize> for NodeId {
+    fn from(index: usize) -> Self {
+        Self(index)
+    }
+}
+
+impl From<NodeId> for usize {
+    fn from(id: NodeId) -> Self {
+        id.0
+    }
+}
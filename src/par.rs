@@ -0,0 +1,273 @@
+use crate::ImpVec;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use orx_concurrent_iter::{ChunkPuller, ConcurrentIter, IntoConcurrentIter};
+use orx_pinned_vec::PinnedVec;
+
+const CHUNK_LEN: usize = 1024;
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Searches for an element satisfying the predicate `pred` in parallel, and returns the lowest index of the matching elements;
+    /// returns None if no element satisfies the predicate.
+    ///
+    /// The result is deterministic; it is always equal to `vec.iter().position(pred)`.
+    ///
+    /// Elements are processed in chunks which never span multiple fragments of the underlying pinned vector.
+    /// Chunks are distributed to the threads in increasing order of their positions,
+    /// and chunks beyond the lowest match found so far are skipped.
+    ///
+    /// The threads are spawned for each call within a [`std::thread::scope`]; no parallel runtime or thread pool is involved.
+    /// The number of threads is determined by [`std::thread::available_parallelism`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// for i in 0..10_000 {
+    ///     vec.imp_push(i);
+    /// }
+    ///
+    /// assert_eq!(vec.par_position(|x| x % 1000 == 999), Some(999));
+    /// assert_eq!(vec.par_position(|x| *x > 10_000), None);
+    /// ```
+    pub fn par_position<F>(&self, pred: F) -> Option<usize>
+    where
+        F: Fn(&T) -> bool + Sync,
+        T: Sync,
+    {
+        let mut chunks = Vec::new();
+        let mut begin = 0;
+        for slice in self.slices(..) {
+            for (c, chunk) in slice.chunks(CHUNK_LEN).enumerate() {
+                chunks.push((begin + c * CHUNK_LEN, chunk));
+            }
+            begin += slice.len();
+        }
+
+        let num_threads = std::thread::available_parallelism()
+            .map(|x| x.get())
+            .unwrap_or(1)
+            .min(chunks.len());
+
+        let next_chunk = AtomicUsize::new(0);
+        let best = AtomicUsize::new(usize::MAX);
+        let search = || loop {
+            let c = next_chunk.fetch_add(1, Ordering::Relaxed);
+            let Some((begin, chunk)) = chunks.get(c) else {
+                break;
+            };
+            if *begin >= best.load(Ordering::Relaxed) {
+                break;
+            }
+            if let Some(i) = chunk.iter().position(&pred) {
+                best.fetch_min(begin + i, Ordering::Relaxed);
+                break;
+            }
+        };
+
+        std::thread::scope(|s| {
+            for _ in 1..num_threads {
+                s.spawn(search);
+            }
+            search();
+        });
+
+        match best.into_inner() {
+            usize::MAX => None,
+            index => Some(index),
+        }
+    }
+
+    /// Appends all elements of the concurrent iterator `iter` to the vector, with a shared reference.
+    ///
+    /// The iterator is drained by multiple threads, each pulling chunks of elements and collecting them locally;
+    /// the collected elements are then appended to the end of the vector by [`imp_push`].
+    /// The order of the appended elements is unspecified: it depends on how the chunks are distributed to the threads.
+    /// Only the multiset of the appended elements is guaranteed to be equal to that of the `iter`.
+    /// See [`par_extend_from_fn`] to append elements produced in parallel in a deterministic order.
+    ///
+    /// The threads are spawned for each call within a [`std::thread::scope`].
+    /// The number of threads is determined by [`std::thread::available_parallelism`].
+    ///
+    /// [`imp_push`]: crate::ImpVec::imp_push
+    /// [`par_extend_from_fn`]: crate::ImpVec::par_extend_from_fn
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is [`seal`]ed and the `iter` yields at least one element.
+    ///
+    /// [`seal`]: crate::ImpVec::seal
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_push(0);
+    ///
+    /// let source: Vec<_> = (1..1000).collect();
+    /// vec.par_extend(source);
+    ///
+    /// assert_eq!(vec.len(), 1000);
+    /// assert_eq!(vec[0], 0);
+    ///
+    /// let mut values: Vec<_> = vec.iter().copied().collect();
+    /// values.sort();
+    /// assert_eq!(values, (0..1000).collect::<Vec<_>>());
+    /// ```
+    pub fn par_extend<I>(&self, iter: I)
+    where
+        I: IntoConcurrentIter<Item = T>,
+        T: Send,
+    {
+        let iter = iter.into_con_iter();
+        let num_threads = std::thread::available_parallelism()
+            .map(|x| x.get())
+            .unwrap_or(1);
+
+        let chunks: Vec<Vec<T>> = std::thread::scope(|s| {
+            let iter = &iter;
+            let handles: Vec<_> = (0..num_threads)
+                .map(|_| s.spawn(move || iter.chunk_puller(CHUNK_LEN).flattened().collect()))
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                .collect()
+        });
+
+        for chunk in chunks {
+            for value in chunk {
+                self.imp_push(value);
+            }
+        }
+    }
+
+    /// Appends `len` elements produced in parallel by the function `f` to the vector, with a shared reference.
+    ///
+    /// The element at position `i` of the produced sequence is `f(i)` for all `i` in `0..len`.
+    /// The production is distributed over threads in contiguous chunks; the produced elements are then
+    /// appended to the end of the vector in the order of their positions `i`, independent of the order the threads complete.
+    ///
+    /// The number of threads is determined by [`std::thread::available_parallelism`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_push(String::from("first"));
+    ///
+    /// vec.par_extend_from_fn(1000, |i| i.to_string());
+    ///
+    /// assert_eq!(vec.len(), 1001);
+    /// assert_eq!(&vec[0], "first");
+    /// assert_eq!(&vec[1000], "999");
+    /// ```
+    pub fn par_extend_from_fn<F>(&self, len: usize, f: F)
+    where
+        F: Fn(usize) -> T + Sync,
+        T: Send,
+    {
+        let num_threads = std::thread::available_parallelism()
+            .map(|x| x.get())
+            .unwrap_or(1)
+            .min(len.div_ceil(CHUNK_LEN))
+            .max(1);
+        let chunk_len = len.div_ceil(num_threads);
+
+        let chunks: Vec<Vec<T>> = std::thread::scope(|s| {
+            let f = &f;
+            let handles: Vec<_> = (0..num_threads)
+                .map(|t| {
+                    let begin = (t * chunk_len).min(len);
+                    let end = (begin + chunk_len).min(len);
+                    s.spawn(move || (begin..end).map(f).collect::<Vec<_>>())
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                .collect()
+        });
+
+        for chunk in chunks {
+            for value in chunk {
+                self.imp_push(value);
+            }
+        }
+    }
+
+    /// Reduces the elements of the vector into a single value in parallel, using the `identity` value and the `combine` function.
+    ///
+    /// The result is equal to the sequential reduction `vec.iter().fold(identity, |a, b| combine(&a, b))`,
+    /// provided that:
+    /// * `combine` is associative, i.e., `combine(&combine(&a, &b), &c) == combine(&a, &combine(&b, &c))`, and
+    /// * `identity` is the identity element of `combine`, i.e., `combine(&identity, &a) == a`.
+    ///
+    /// The function is not required to be commutative since the partial results are combined in the order of the positions.
+    ///
+    /// Elements are processed in chunks which never span multiple fragments of the underlying pinned vector.
+    /// Each thread reduces a contiguous range of chunks into a partial result; the partial results are then combined in order.
+    ///
+    /// The number of threads is determined by [`std::thread::available_parallelism`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// for i in 0..10_000u64 {
+    ///     vec.imp_push(i);
+    /// }
+    ///
+    /// assert_eq!(vec.par_reduce(0, |a, b| a + b), 49_995_000);
+    /// assert_eq!(vec.par_reduce(0, |a, b| *a.max(b)), 9_999);
+    /// ```
+    pub fn par_reduce<F>(&self, identity: T, combine: F) -> T
+    where
+        F: Fn(&T, &T) -> T + Sync,
+        T: Send + Sync + Clone,
+    {
+        let mut chunks = Vec::new();
+        for slice in self.slices(..) {
+            chunks.extend(slice.chunks(CHUNK_LEN));
+        }
+
+        let num_threads = std::thread::available_parallelism()
+            .map(|x| x.get())
+            .unwrap_or(1)
+            .min(chunks.len())
+            .max(1);
+        let chunks_per_thread = chunks.len().div_ceil(num_threads);
+
+        let partials: Vec<T> = std::thread::scope(|s| {
+            let (identity, combine) = (&identity, &combine);
+            let handles: Vec<_> = chunks
+                .chunks(chunks_per_thread.max(1))
+                .map(|thread_chunks| {
+                    s.spawn(move || {
+                        let mut acc = identity.clone();
+                        for x in thread_chunks.iter().flat_map(|chunk| chunk.iter()) {
+                            acc = combine(&acc, x);
+                        }
+                        acc
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|h| h.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+                .collect()
+        });
+
+        partials
+            .iter()
+            .fold(identity, |acc, partial| combine(&acc, partial))
+    }
+}
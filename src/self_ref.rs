@@ -0,0 +1,311 @@
+use crate::ImpVec;
+use orx_pinned_vec::PinnedVec;
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Removes the last element from the vector and returns it, or None if it is empty.
+    ///
+    /// Prior to popping, `unlink` is called with a mutable reference to the vector and the index of the last element.
+    /// This gives the caller the chance to detach all references pointing at the element to be popped,
+    /// which is required when the elements of the vector hold references to each other.
+    ///
+    /// Note that the element popped is the last element after `unlink` is called;
+    /// therefore, `unlink` is not expected to change the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// struct Node {
+    ///     value: char,
+    ///     next: Option<*const Node>,
+    /// }
+    ///
+    /// let mut vec = ImpVec::new();
+    /// vec.imp_push(Node { value: 'a', next: None });
+    /// vec.imp_push(Node { value: 'b', next: None });
+    ///
+    /// // a -> b
+    /// let b = &vec[1] as *const Node;
+    /// vec[0].next = Some(b);
+    ///
+    /// let popped = vec.pop_with(|vec, last| {
+    ///     assert_eq!(last, 1);
+    ///     vec[last - 1].next = None;
+    /// });
+    ///
+    /// assert_eq!(popped.map(|x| x.value), Some('b'));
+    /// assert_eq!(vec.len(), 1);
+    /// assert!(vec[0].next.is_none());
+    /// ```
+    pub fn pop_with<F>(&mut self, unlink: F) -> Option<T>
+    where
+        F: FnOnce(&mut Self, usize),
+    {
+        match self.len() {
+            0 => None,
+            len => {
+                unlink(self, len - 1);
+                self.pop()
+            }
+        }
+    }
+
+    /// Shortens the vector, keeping the first `len` elements and dropping the rest, with a shared reference.
+    ///
+    /// If `len` is greater than or equal to the length of the vector, this has no effect.
+    ///
+    /// Memory positions of the first `len` elements are not affected; dropped elements are not moved.
+    /// This is useful while cleaning up self-referential collections where the vector is only accessible through a shared reference.
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe since dropping elements with a shared reference might leave dangling references.
+    /// The caller must guarantee that there exists no live reference pointing to any of the dropped elements;
+    /// neither from outside of the vector nor from the remaining elements of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&[0, 1, 2, 3, 4]);
+    ///
+    /// let first = &vec[0];
+    ///
+    /// unsafe { vec.imp_truncate(2) };
+    /// assert_eq!(&vec, [0, 1].as_slice());
+    /// assert_eq!(first, &0);
+    /// ```
+    pub unsafe fn imp_truncate(&self, len: usize) {
+        self.forget_capacity();
+        self.pinned_mut().truncate(len);
+    }
+
+    /// Calls `f` on a mutable reference to each element of the vector in order.
+    ///
+    /// Unlike `iter_mut`, each mutable borrow is bounded to a single call of `f`;
+    /// therefore, no two mutable references to elements are alive at the same time.
+    /// This is the preferred way to mutate fields of the elements of self-referential collections.
+    ///
+    /// Note that when the elements hold references or pointers to each other,
+    /// `f` must not read through these references to the element currently being mutated;
+    /// it is safe to mutate the plain data fields of the elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// struct Node {
+    ///     data: u32,
+    ///     next: Option<*const Node>,
+    /// }
+    ///
+    /// let mut vec = ImpVec::new();
+    /// vec.imp_push(Node { data: 1, next: None });
+    /// vec.imp_push(Node { data: 2, next: None });
+    ///
+    /// let b = &vec[1] as *const Node;
+    /// vec[0].next = Some(b);
+    ///
+    /// vec.for_each_mut(|node| node.data *= 10);
+    ///
+    /// assert_eq!(vec[0].data, 10);
+    /// assert_eq!(unsafe { &*vec[0].next.unwrap() }.data, 20);
+    /// ```
+    pub fn for_each_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.iter_mut().for_each(f)
+    }
+
+    /// Follows the `next` links starting from the element at position `start`, and returns the index of the
+    /// first element of the cycle if the chain of links is cyclic; returns None if the chain terminates.
+    ///
+    /// The `next` function returns the element that the given element links to, or None if it is the last element of its chain.
+    /// Cycle detection uses Floyd's algorithm; hence, it requires *O(1)* additional memory and visits each element
+    /// of the chain a constant number of times.
+    ///
+    /// Returns None if `start` is out of bounds.
+    /// Further, if the chain is cyclic but the first element of the cycle is not an element of this vector,
+    /// None is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// struct Node<'a> {
+    ///     value: char,
+    ///     next: core::cell::Cell<Option<&'a Node<'a>>>,
+    /// }
+    ///
+    /// let vec = ImpVec::new();
+    /// for value in ['a', 'b', 'c', 'd'] {
+    ///     vec.imp_push(Node { value, next: Default::default() });
+    /// }
+    ///
+    /// // a -> b -> c -> d
+    /// vec[0].next.set(Some(&vec[1]));
+    /// vec[1].next.set(Some(&vec[2]));
+    /// vec[2].next.set(Some(&vec[3]));
+    /// assert_eq!(vec.detect_next_cycle(0, |x| x.next.get()), None);
+    ///
+    /// // a -> b -> c -> d -> b
+    /// vec[3].next.set(Some(&vec[1]));
+    /// assert_eq!(vec.detect_next_cycle(0, |x| x.next.get()), Some(1));
+    /// assert_eq!(vec[1].value, 'b');
+    /// ```
+    pub fn detect_next_cycle<'a, F>(&'a self, start: usize, next: F) -> Option<usize>
+    where
+        F: Fn(&'a T) -> Option<&'a T>,
+    {
+        let start = self.get(start)?;
+
+        let mut slow = start;
+        let mut fast = start;
+        loop {
+            slow = next(slow)?;
+            fast = next(next(fast)?)?;
+            if core::ptr::eq(slow, fast) {
+                break;
+            }
+        }
+
+        slow = start;
+        while !core::ptr::eq(slow, fast) {
+            slow = next(slow)?;
+            fast = next(fast)?;
+        }
+
+        self.index_of_ptr(slow)
+    }
+
+    /// Follows the `next` links starting from the element at position `start`, and returns the number of elements
+    /// on the cycle if the chain of links returns back to `start`; returns None otherwise.
+    ///
+    /// The `next` function returns the element that the given element links to, or None if it is the last element of its chain.
+    /// None is returned if the chain terminates, or if the chain enters a cycle which does not contain `start`.
+    /// The cycle length is computed by Brent's algorithm; hence, it requires *O(1)* additional memory and terminates in both cases.
+    ///
+    /// Returns None if `start` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// struct Node<'a> {
+    ///     next: core::cell::Cell<Option<&'a Node<'a>>>,
+    /// }
+    ///
+    /// let vec = ImpVec::new();
+    /// for _ in 0..4 {
+    ///     vec.imp_push(Node { next: Default::default() });
+    /// }
+    ///
+    /// // 0 -> 1 -> 2 -> 3
+    /// vec[0].next.set(Some(&vec[1]));
+    /// vec[1].next.set(Some(&vec[2]));
+    /// vec[2].next.set(Some(&vec[3]));
+    /// assert_eq!(vec.cycle_len(0, |x| x.next.get()), None);
+    ///
+    /// // 0 -> 1 -> 2 -> 3 -> 1
+    /// vec[3].next.set(Some(&vec[1]));
+    /// assert_eq!(vec.cycle_len(0, |x| x.next.get()), None);
+    /// assert_eq!(vec.cycle_len(1, |x| x.next.get()), Some(3));
+    /// ```
+    pub fn cycle_len<'a, F>(&'a self, start: usize, next: F) -> Option<usize>
+    where
+        F: Fn(&'a T) -> Option<&'a T>,
+    {
+        let start = self.get(start)?;
+
+        let mut power = 1;
+        let mut len = 1;
+        let mut tortoise = start;
+        let mut hare = next(start)?;
+        while !core::ptr::eq(tortoise, hare) {
+            if power == len {
+                tortoise = hare;
+                power *= 2;
+                len = 0;
+            }
+            hare = next(hare)?;
+            len += 1;
+        }
+
+        let mut current = start;
+        for _ in 0..len {
+            current = next(current)?;
+        }
+        core::ptr::eq(current, start).then_some(len)
+    }
+
+    /// Detaches the element at position `idx` from the doubly-linked list that it belongs to.
+    ///
+    /// The `links` function returns mutable references to the `(prev, next)` links of an element.
+    /// The previous element of `idx` is linked to its next element and vice versa; then, both links of `idx` are cleared.
+    /// The element itself stays in the vector at its position; only the links are updated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is out of bounds, or if a link of the element at `idx` does not point to an element of this vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// struct Node {
+    ///     value: char,
+    ///     prev: Option<*const Node>,
+    ///     next: Option<*const Node>,
+    /// }
+    ///
+    /// let mut vec = ImpVec::new();
+    /// for value in ['a', 'b', 'c'] {
+    ///     vec.imp_push(Node { value, prev: None, next: None });
+    /// }
+    ///
+    /// // a <-> b <-> c
+    /// for i in 0..2 {
+    ///     let (current, next) = (&vec[i] as *const Node, &vec[i + 1] as *const Node);
+    ///     vec[i].next = Some(next);
+    ///     vec[i + 1].prev = Some(current);
+    /// }
+    ///
+    /// vec.unlink(1, |x| (&mut x.prev, &mut x.next));
+    ///
+    /// // a <-> c
+    /// assert_eq!(vec[0].next, Some(&vec[2] as *const Node));
+    /// assert_eq!(vec[2].prev, Some(&vec[0] as *const Node));
+    /// assert!(vec[1].prev.is_none() && vec[1].next.is_none());
+    /// ```
+    pub fn unlink<F>(&mut self, idx: usize, links: F)
+    where
+        F: Fn(&mut T) -> (&mut Option<*const T>, &mut Option<*const T>),
+    {
+        let (prev, next) = {
+            let (prev, next) = links(&mut self[idx]);
+            (prev.take(), next.take())
+        };
+
+        if let Some(prev_ptr) = prev {
+            let prev_idx = self
+                .index_of_ptr(prev_ptr)
+                .expect("prev link does not point to an element of the vector");
+            *links(&mut self[prev_idx]).1 = next;
+        }
+
+        if let Some(next_ptr) = next {
+            let next_idx = self
+                .index_of_ptr(next_ptr)
+                .expect("next link does not point to an element of the vector");
+            *links(&mut self[next_idx]).0 = prev;
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::ImpVec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use orx_pinned_vec::PinnedVec;
+
+impl<T, P: PinnedVec<T>> ImpVec<T, P> {
+    /// Returns the indices that would sort the elements of the vector, without moving the elements.
+    ///
+    /// The element at position `argsort()[i]` is the `i`-th smallest element of the vector.
+    /// The sort is stable; i.e., positions of equal elements are kept in their original relative order.
+    ///
+    /// Since the storage is left untouched, all references to the elements remain valid;
+    /// which makes it suitable for obtaining a sorted view of self-referential collections.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&['c', 'a', 'd', 'b']);
+    ///
+    /// let indices = vec.argsort();
+    /// assert_eq!(indices, [1, 3, 0, 2]);
+    ///
+    /// let sorted: String = indices.iter().map(|i| vec[*i]).collect();
+    /// assert_eq!(sorted, "abcd");
+    /// ```
+    pub fn argsort(&self) -> Vec<usize>
+    where
+        T: Ord,
+    {
+        self.argsort_by(T::cmp)
+    }
+
+    /// Returns the indices that would sort the elements of the vector with respect to the `compare` function,
+    /// without moving the elements.
+    ///
+    /// The element at position `argsort_by(compare)[i]` is the `i`-th smallest element of the vector with respect to `compare`.
+    /// The sort is stable; i.e., positions of equal elements are kept in their original relative order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use orx_imp_vec::*;
+    ///
+    /// let vec = ImpVec::new();
+    /// vec.imp_extend_from_slice(&["xyz", "a", "bc", "d"]);
+    ///
+    /// let indices = vec.argsort_by(|a, b| b.len().cmp(&a.len()));
+    /// assert_eq!(indices, [0, 2, 1, 3]);
+    /// ```
+    pub fn argsort_by<F>(&self, mut compare: F) -> Vec<usize>
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let elements: Vec<&T> = self.iter().collect();
+        let mut indices: Vec<usize> = (0..elements.len()).collect();
+        indices.sort_by(|i, j| compare(elements[*i], elements[*j]));
+        indices
+    }
+}
@@ -0,0 +1,403 @@
+use orx_imp_vec::*;
+
+#[test]
+fn index_or() {
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..10 {
+        vec.imp_push(i);
+    }
+
+    let default = 42;
+    for i in 0..10 {
+        assert_eq!(vec.index_or(i, &default), &i);
+    }
+    assert!(std::ptr::eq(vec.index_or(10, &default), &default));
+    assert!(std::ptr::eq(vec.index_or(usize::MAX, &default), &default));
+}
+
+#[test]
+fn get_adjacent_mut() {
+    struct Node<'a> {
+        value: usize,
+        next: Option<&'a Node<'a>>,
+    }
+
+    // list: 9 -> 8 -> ... -> 0
+    let vec = ImpVec::with_linear_growth(2);
+    let mut prev = None;
+    for i in 0..10 {
+        prev = Some(vec.imp_push_get_ref(Node {
+            value: i,
+            next: prev,
+        }));
+    }
+
+    // remove node 5 from the list: 6 -> 4
+    let node4 = &vec[4];
+    let (node5, node6) = unsafe { vec.get_adjacent_mut(5) }.unwrap();
+    assert_eq!(node5.value, 5);
+    assert_eq!(node6.value, 6);
+    node6.next = Some(node4);
+    node5.next = None;
+
+    let mut values = vec![];
+    let mut current = Some(&vec[9]);
+    while let Some(node) = current {
+        values.push(node.value);
+        current = node.next;
+    }
+    assert_eq!(values, [9, 8, 7, 6, 4, 3, 2, 1, 0]);
+    assert!(vec[5].next.is_none());
+
+    assert!(unsafe { vec.get_adjacent_mut(9) }.is_none());
+    assert!(unsafe { vec.get_adjacent_mut(usize::MAX) }.is_none());
+}
+
+#[test]
+fn try_index() {
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..10 {
+        vec.imp_push(i);
+    }
+
+    for i in 0..10 {
+        assert_eq!(vec.try_index(i), Ok(&i));
+    }
+
+    let error = vec.try_index(12).unwrap_err();
+    assert_eq!(error.index, 12);
+    assert_eq!(error.len, 10);
+    assert_eq!(
+        error.to_string(),
+        "index out of bounds: the len is 10 but the index is 12"
+    );
+
+    vec.imp_push(10);
+    assert_eq!(vec.try_index(10), Ok(&10));
+    assert_eq!(vec.try_index(11), Err(IndexError { index: 11, len: 11 }));
+}
+
+#[test]
+fn first_ptr() {
+    let mut vec = ImpVec::with_linear_growth(2);
+    assert!(vec.first_ptr().is_none());
+    assert!(vec.first_ptr_mut().is_none());
+
+    vec.imp_push(0);
+    let ptr = vec.first_ptr().unwrap();
+    assert_eq!(ptr, &vec[0] as *const i32);
+
+    for i in 1..100 {
+        vec.imp_push(i);
+    }
+    assert_eq!(vec.first_ptr(), Some(ptr));
+    assert_eq!(unsafe { *ptr }, 0);
+
+    let ptr_mut = vec.first_ptr_mut().unwrap();
+    assert_eq!(ptr_mut as *const i32, ptr);
+    unsafe { *ptr_mut = 42 };
+    assert_eq!(vec[0], 42);
+}
+
+#[test]
+fn last_mut() {
+    fn test<P: PinnedVec<String>>(mut vec: ImpVec<String, P>) {
+        assert!(vec.last_mut().is_none());
+
+        for i in 0..100 {
+            vec.imp_push(i.to_string());
+            let last = vec.last_mut().expect("not empty");
+            assert_eq!(last, &i.to_string());
+            last.push('!');
+        }
+
+        assert_eq!(vec.len(), 100);
+        for i in 0..100 {
+            assert_eq!(vec[i], format!("{}!", i));
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+fn imp_last_mut() {
+    fn test<P: PinnedVec<String>>(vec: ImpVec<String, P>) {
+        assert!(unsafe { vec.imp_last_mut() }.is_none());
+
+        let mut refs = vec![];
+        for i in 0..100 {
+            refs.push(vec.imp_push_get_ref(i.to_string()));
+            vec.imp_push(String::new());
+            let last = unsafe { vec.imp_last_mut() }.expect("not empty");
+            last.push_str(&format!("after {}", i));
+        }
+
+        assert_eq!(vec.len(), 200);
+        for (i, x) in refs.iter().enumerate() {
+            assert_eq!(*x, &i.to_string());
+            assert!(core::ptr::eq(*x, &vec[2 * i]));
+            assert_eq!(vec[2 * i + 1], format!("after {}", i));
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(200));
+}
+
+#[test]
+fn get_slice() {
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..10 {
+        vec.imp_push(i);
+    }
+
+    // intra-fragment
+    assert_eq!(vec.get_slice(0..4), Some([0, 1, 2, 3].as_slice()));
+    assert_eq!(vec.get_slice(5..7), Some([5, 6].as_slice()));
+    assert_eq!(vec.get_slice(8..10), Some([8, 9].as_slice()));
+    assert_eq!(vec.get_slice(9..10), Some([9].as_slice()));
+    assert_eq!(vec.get_slice(3..3), Some([].as_slice()));
+    assert_eq!(vec.get_slice(10..10), Some([].as_slice()));
+
+    // cross-fragment
+    assert_eq!(vec.get_slice(3..5), None);
+    assert_eq!(vec.get_slice(0..10), None);
+
+    // out-of-bounds
+    assert_eq!(vec.get_slice(8..11), None);
+    assert_eq!(vec.get_slice(11..12), None);
+    assert_eq!(vec.get_slice(11..11), None);
+
+    let fixed = ImpVec::with_fixed_capacity(10);
+    fixed.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    assert_eq!(fixed.get_slice(0..6), Some([0, 1, 2, 3, 4, 5].as_slice()));
+    assert_eq!(fixed.get_slice(2..4), Some([2, 3].as_slice()));
+    assert_eq!(fixed.get_slice(2..7), None);
+}
+
+#[test]
+fn get_slice_mut() {
+    let mut vec = ImpVec::with_linear_growth(2);
+    for i in 0..10 {
+        vec.imp_push(i);
+    }
+
+    // intra-fragment
+    vec.get_slice_mut(0..4)
+        .unwrap()
+        .copy_from_slice(&[10, 11, 12, 13]);
+    vec.get_slice_mut(5..7).unwrap().fill(42);
+    vec.get_slice_mut(9..10).unwrap()[0] = 7;
+    assert_eq!(vec.get_slice_mut(3..3), Some([].as_mut_slice()));
+    assert_eq!(vec.get_slice_mut(10..10), Some([].as_mut_slice()));
+    assert_eq!(&vec, [10, 11, 12, 13, 4, 42, 42, 7, 8, 7].as_slice());
+
+    // cross-fragment
+    assert_eq!(vec.get_slice_mut(3..5), None);
+    assert_eq!(vec.get_slice_mut(0..10), None);
+
+    // out-of-bounds
+    assert_eq!(vec.get_slice_mut(8..11), None);
+    assert_eq!(vec.get_slice_mut(11..12), None);
+    assert_eq!(vec.get_slice_mut(11..11), None);
+
+    let mut fixed = ImpVec::with_fixed_capacity(10);
+    fixed.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    fixed.get_slice_mut(1..5).unwrap().reverse();
+    assert_eq!(&fixed, [0, 4, 3, 2, 1, 5].as_slice());
+    assert_eq!(fixed.get_slice_mut(2..7), None);
+}
+
+#[test]
+fn get_with_fragment() {
+    fn test<P: PinnedVec<usize>>(vec: ImpVec<usize, P>) {
+        assert_eq!(vec.get_with_fragment(0), None);
+
+        for i in 0..100 {
+            vec.imp_push(i);
+        }
+
+        for i in 0..100 {
+            let (x, slice) = vec.get_with_fragment(i).expect("in bounds");
+            assert_eq!(x, &i);
+            assert!(core::ptr::eq(x, &vec[i]));
+            assert!(core::ptr::eq(x, &slice[0]));
+            assert!(i + slice.len() <= 100);
+        }
+        assert_eq!(vec.get_with_fragment(100), None);
+
+        // jumping over fragments visits all elements in order
+        let mut collected = vec![];
+        let mut i = 0;
+        while let Some((_, slice)) = vec.get_with_fragment(i) {
+            collected.extend_from_slice(slice);
+            i += slice.len();
+        }
+        assert_eq!(collected, (0..100).collect::<Vec<_>>());
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(100));
+
+    let vec = ImpVec::with_linear_growth(2);
+    vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5]);
+    assert_eq!(vec.get_with_fragment(3), Some((&3, [3].as_slice())));
+    assert_eq!(vec.get_with_fragment(5), Some((&5, [5].as_slice())));
+}
+
+#[test]
+fn index_from_ptr() {
+    fn test<P: PinnedVec<usize>>(vec: ImpVec<usize, P>) {
+        for i in 0..100 {
+            vec.imp_push(i);
+        }
+        let ptrs: Vec<_> = (0..100).map(|i| vec.get_ptr(i).unwrap()).collect();
+
+        for i in 100..200 {
+            vec.imp_push(i);
+        }
+
+        for (i, ptr) in ptrs.iter().enumerate() {
+            assert_eq!(vec.index_from_ptr(*ptr), Some(i));
+        }
+        for i in 0..200 {
+            assert_eq!(vec.index_from_ptr(&vec[i] as *const usize), Some(i));
+        }
+
+        let other = [42usize];
+        assert_eq!(vec.index_from_ptr(&other[0] as *const usize), None);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(3));
+    test(ImpVec::with_fixed_capacity(200));
+}
+
+#[test]
+fn contains_ref() {
+    fn test<P: PinnedVec<usize>, Q: PinnedVec<usize>>(a: ImpVec<usize, P>, b: ImpVec<usize, Q>) {
+        let mut refs_a = vec![];
+        let mut refs_b = vec![];
+        for i in 0..100 {
+            refs_a.push(a.imp_push_get_ref(i));
+            refs_b.push(b.imp_push_get_ref(i));
+        }
+
+        for (x, y) in refs_a.iter().zip(&refs_b) {
+            assert_eq!(x, y);
+            assert!(a.contains_ref(x));
+            assert!(!a.contains_ref(y));
+            assert!(b.contains_ref(y));
+            assert!(!b.contains_ref(x));
+        }
+
+        let outside = 42;
+        assert!(!a.contains_ref(&outside));
+        assert!(!b.contains_ref(&outside));
+    }
+
+    test(ImpVec::new(), ImpVec::new());
+    test(
+        ImpVec::with_recursive_growth(),
+        ImpVec::with_linear_growth(2),
+    );
+    test(ImpVec::with_fixed_capacity(100), ImpVec::new());
+}
+
+#[test]
+fn iter_rev_ptr() {
+    fn test<P: PinnedVec<usize>>(vec: ImpVec<usize, P>) {
+        assert_eq!(vec.iter_rev_ptr().count(), 0);
+
+        for i in 0..100 {
+            vec.imp_push(i);
+        }
+        let ptrs: Vec<_> = vec.iter_rev_ptr().collect();
+        assert_eq!(ptrs.len(), 100);
+
+        for i in 100..200 {
+            vec.imp_push(i);
+        }
+
+        for (r, ptr) in ptrs.iter().enumerate() {
+            let i = 99 - r;
+            assert_eq!(*ptr, &vec[i] as *const usize);
+            assert_eq!(unsafe { **ptr }, i);
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(3));
+    test(ImpVec::with_fixed_capacity(200));
+}
+
+#[test]
+fn element_bytes() {
+    fn test<P: PinnedVec<u32>>(vec: ImpVec<u32, P>) {
+        for i in 0..100 {
+            vec.imp_push(i as u32 * 0x01010101);
+        }
+
+        for i in 0..100 {
+            let bytes = unsafe { vec.element_bytes(i) }.expect("in bounds");
+            assert_eq!(bytes, vec[i].to_ne_bytes().as_slice());
+            assert_eq!(bytes.as_ptr(), &vec[i] as *const u32 as *const u8);
+
+            let array: [u8; 4] = bytes.try_into().expect("4 bytes");
+            #[cfg(target_endian = "little")]
+            assert_eq!(u32::from_le_bytes(array), vec[i]);
+            #[cfg(target_endian = "big")]
+            assert_eq!(u32::from_be_bytes(array), vec[i]);
+        }
+
+        assert_eq!(unsafe { vec.element_bytes(100) }, None);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+fn get_ref_for_lifetime() {
+    fn build<P: PinnedVec<String>>(vec: &ImpVec<String, P>) -> Vec<&String> {
+        let mut refs = vec![];
+        for i in 0..100 {
+            vec.imp_push(i.to_string());
+            if i % 10 == 0 {
+                refs.push(vec.get_ref_for_lifetime(i).expect("in bounds"));
+            }
+        }
+        assert!(vec.get_ref_for_lifetime(100).is_none());
+        refs
+    }
+
+    fn test<P: PinnedVec<String>>(vec: ImpVec<String, P>) {
+        let refs = build(&vec);
+        for i in 100..200 {
+            vec.imp_push(i.to_string());
+        }
+
+        assert_eq!(refs.len(), 10);
+        for (i, x) in refs.iter().enumerate() {
+            assert_eq!(*x, &(i * 10).to_string());
+            assert!(core::ptr::eq(*x, &vec[i * 10]));
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(200));
+}
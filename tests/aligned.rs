@@ -0,0 +1,51 @@
+use orx_imp_vec::*;
+
+#[test]
+fn into_aligned_vec() {
+    fn test<P: PinnedVec<f64>>(vec: ImpVec<f64, P>, align: usize) {
+        for i in 0..1000 {
+            vec.imp_push(i as f64 * 0.5);
+        }
+
+        let aligned = vec.into_aligned_vec(align);
+        assert_eq!(aligned.as_ptr() as usize % align, 0);
+        assert_eq!(aligned.align(), align.max(core::mem::align_of::<f64>()));
+        assert_eq!(aligned.len(), 1000);
+        for (i, x) in aligned.iter().enumerate() {
+            assert_eq!(*x, i as f64 * 0.5);
+        }
+    }
+
+    for align in [1, 8, 16, 32, 64, 4096] {
+        test(ImpVec::new(), align);
+        test(ImpVec::with_recursive_growth(), align);
+        test(ImpVec::with_linear_growth(3), align);
+        test(ImpVec::with_fixed_capacity(1000), align);
+    }
+}
+
+#[test]
+fn into_aligned_vec_mut() {
+    let vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[1u8, 2, 3, 4, 5, 6, 7]);
+
+    let mut aligned = vec.into_aligned_vec(32);
+    aligned.iter_mut().for_each(|x| *x *= 2);
+    assert_eq!(&aligned[..], &[2, 4, 6, 8, 10, 12, 14]);
+    assert_eq!(aligned.as_ptr() as usize % 32, 0);
+}
+
+#[test]
+fn into_aligned_vec_empty() {
+    let vec = ImpVec::<u32>::new();
+    let aligned = vec.into_aligned_vec(64);
+    assert!(aligned.is_empty());
+    assert_eq!(aligned.as_ptr() as usize % 64, 0);
+}
+
+#[test]
+#[should_panic(expected = "not a power of two")]
+fn into_aligned_vec_invalid_align() {
+    let vec = ImpVec::<u32>::new();
+    let _ = vec.into_aligned_vec(24);
+}
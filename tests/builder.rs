@@ -0,0 +1,77 @@
+use orx_imp_vec::*;
+
+fn push_and_validate<P: PinnedVec<String>>(vec: ImpVec<String, P>, n: usize) {
+    let first = vec.imp_push_get_ref(0.to_string());
+    for i in 1..n {
+        vec.imp_push(i.to_string());
+    }
+    assert_eq!(first, "0");
+    for i in 0..n {
+        assert_eq!(vec[i], i.to_string());
+    }
+}
+
+#[test]
+fn builder_default() {
+    let vec: ImpVec<String> = ImpVecBuilder::new().build();
+    assert_eq!(vec.capacity(), 4);
+    push_and_validate(vec, 100);
+
+    let vec: ImpVec<String> = ImpVecBuilder::default().build();
+    push_and_validate(vec, 100);
+}
+
+#[test]
+fn builder_doubling() {
+    let vec: ImpVec<String, SplitVec<String, Doubling>> =
+        ImpVecBuilder::new().fixed(3).doubling().build();
+    assert_eq!(vec.capacity(), 4);
+    push_and_validate(vec, 100);
+}
+
+#[test]
+fn builder_linear() {
+    let vec: ImpVec<String, SplitVec<String, Linear>> = ImpVecBuilder::new().linear(3).build();
+    assert_eq!(vec.capacity(), 8);
+    push_and_validate(vec, 100);
+}
+
+#[test]
+#[should_panic(
+    expected = "constant_fragment_capacity_exponent 100 is too large: the fragment capacity 2 ^ 100 overflows usize::MAX"
+)]
+fn builder_linear_overflowing_exponent() {
+    let _: ImpVec<char, _> = ImpVecBuilder::new().linear(100).build();
+}
+
+#[test]
+fn builder_recursive() {
+    let vec: ImpVec<String, SplitVec<String, Recursive>> = ImpVecBuilder::new().recursive().build();
+    assert_eq!(vec.capacity(), 4);
+    push_and_validate(vec, 100);
+
+    let vec: ImpVec<String, SplitVec<String, Recursive>> = ImpVecBuilder::new()
+        .recursive()
+        .first_fragment_capacity(50)
+        .build();
+    assert_eq!(vec.capacity(), 50);
+    assert_eq!(vec.fragments().len(), 1);
+    push_and_validate(vec, 100);
+}
+
+#[test]
+fn builder_fixed() {
+    let vec: ImpVec<String, FixedVec<String>> = ImpVecBuilder::new().fixed(100).build();
+    assert_eq!(vec.capacity(), 100);
+    push_and_validate(vec, 100);
+}
+
+#[test]
+fn builder_recursive_zero_first_fragment_capacity() {
+    let vec: ImpVec<String, SplitVec<String, Recursive>> = ImpVecBuilder::new()
+        .recursive()
+        .first_fragment_capacity(0)
+        .build();
+    assert_eq!(vec.capacity(), 4);
+    push_and_validate(vec, 100);
+}
@@ -0,0 +1,157 @@
+use orx_imp_vec::*;
+
+#[test]
+fn starts_with() {
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..10 {
+        vec.imp_push(i);
+    }
+    let all: Vec<_> = (0..10).collect();
+
+    for n in 0..=10 {
+        assert!(vec.starts_with(&all[..n]));
+    }
+    assert!(vec.starts_with(&[0, 1, 2, 3, 4, 5]));
+    assert!(!vec.starts_with(&[0, 1, 2, 3, 42, 5]));
+    assert!(!vec.starts_with(&[0, 1, 2, 3, 4, 42]));
+    assert!(!vec.starts_with(&[1]));
+
+    let mut longer = all.clone();
+    longer.push(10);
+    assert!(!vec.starts_with(&longer));
+
+    let empty = ImpVec::<i32>::new();
+    assert!(empty.starts_with(&[]));
+    assert!(!empty.starts_with(&[0]));
+}
+
+#[test]
+fn ends_with() {
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..10 {
+        vec.imp_push(i);
+    }
+    let all: Vec<_> = (0..10).collect();
+
+    for n in 0..=10 {
+        assert!(vec.ends_with(&all[n..]));
+    }
+    assert!(vec.ends_with(&[2, 3, 4, 5, 6, 7, 8, 9]));
+    assert!(!vec.ends_with(&[2, 3, 42, 5, 6, 7, 8, 9]));
+    assert!(!vec.ends_with(&[42, 3, 4, 5, 6, 7, 8, 9]));
+    assert!(!vec.ends_with(&[8]));
+
+    let mut longer = vec![-1];
+    longer.extend_from_slice(&all);
+    assert!(!vec.ends_with(&longer));
+
+    let empty = ImpVec::<i32>::new();
+    assert!(empty.ends_with(&[]));
+    assert!(!empty.ends_with(&[0]));
+}
+
+#[test]
+fn eq_prefix() {
+    let vec = ImpVec::with_linear_growth(2);
+    let mut other = ImpVec::with_doubling_growth();
+    for i in 0..10 {
+        vec.imp_push(i);
+        other.imp_push(i);
+    }
+
+    // equal
+    for k in 0..=10 {
+        assert!(vec.eq_prefix(&other, k));
+        assert!(other.eq_prefix(&vec, k));
+    }
+
+    // differing
+    other[6] = 42;
+    for k in 0..=6 {
+        assert!(vec.eq_prefix(&other, k));
+    }
+    for k in 7..=10 {
+        assert!(!vec.eq_prefix(&other, k));
+        assert!(!other.eq_prefix(&vec, k));
+    }
+
+    // short
+    let short = ImpVec::with_fixed_capacity(4);
+    short.imp_extend_from_slice(&[0, 1, 2, 3]);
+    for k in 0..=4 {
+        assert!(vec.eq_prefix(&short, k));
+        assert!(short.eq_prefix(&vec, k));
+    }
+    for k in 5..=10 {
+        assert!(!vec.eq_prefix(&short, k));
+        assert!(!short.eq_prefix(&vec, k));
+    }
+    assert!(!vec.eq_prefix(&vec, 11));
+
+    let empty = ImpVec::<i32>::new();
+    assert!(empty.eq_prefix(&vec, 0));
+    assert!(!empty.eq_prefix(&vec, 1));
+}
+
+#[test]
+fn eq_unordered() {
+    let vec = ImpVec::with_linear_growth(2);
+    let other = ImpVec::with_doubling_growth();
+    for i in 0..100 {
+        vec.imp_push((i % 17).to_string());
+        other.imp_push(((99 - i) % 17).to_string());
+    }
+    assert!(vec.eq_unordered(&other));
+    assert!(other.eq_unordered(&vec));
+    assert!(vec.eq_unordered(&vec));
+
+    // different multiplicities
+    other.imp_push(0.to_string());
+    vec.imp_push(1.to_string());
+    assert!(!vec.eq_unordered(&other));
+
+    // different lengths
+    other.imp_push(1.to_string());
+    assert!(!vec.eq_unordered(&other));
+
+    vec.imp_push(0.to_string());
+    assert!(vec.eq_unordered(&other));
+
+    let empty = ImpVec::<String>::new();
+    assert!(empty.eq_unordered(&ImpVec::<String, FixedVec<String>>::with_fixed_capacity(3)));
+    assert!(!empty.eq_unordered(&vec));
+}
+
+#[test]
+fn approx_eq() {
+    fn test<P: PinnedVec<f64>, Q: PinnedVec<f64>>(a: ImpVec<f64, P>, b: ImpVec<f64, Q>) {
+        assert!(a.approx_eq(&b, 0.0));
+
+        for i in 0..100 {
+            a.imp_push(i as f64 / 3.0);
+            b.imp_push(i as f64 / 3.0 + 1e-12);
+        }
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(b.approx_eq(&a, 1e-9));
+        assert!(!a.approx_eq(&b, 1e-15));
+
+        b.imp_push(0.0);
+        assert!(!a.approx_eq(&b, 1.0));
+
+        a.imp_push(f64::NAN);
+        assert!(!a.approx_eq(&b, f64::INFINITY));
+    }
+
+    test(ImpVec::new(), ImpVec::new());
+    test(
+        ImpVec::with_recursive_growth(),
+        ImpVec::with_linear_growth(2),
+    );
+    test(ImpVec::with_fixed_capacity(101), ImpVec::new());
+
+    let a: ImpVec<f32> = ImpVec::new();
+    a.imp_extend_from_slice(&[1.0, 2.0]);
+    let b: ImpVec<f32> = ImpVec::new();
+    b.imp_extend_from_slice(&[1.0 + 1e-7, 2.0]);
+    assert!(a.approx_eq(&b, 1e-6));
+}
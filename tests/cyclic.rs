@@ -0,0 +1,124 @@
+use orx_imp_vec::*;
+
+struct Node {
+    value: usize,
+    next: *const Node,
+    prev: *const Node,
+}
+
+impl Node {
+    fn new(value: usize) -> Self {
+        Self {
+            value,
+            next: core::ptr::null(),
+            prev: core::ptr::null(),
+        }
+    }
+}
+
+#[test]
+fn build_cyclic_ring() {
+    fn test<P: PinnedVec<Node>>(pinned: P) {
+        let n = 100;
+        let vec = ImpVec::build_cyclic(pinned, |b| {
+            assert!(b.is_empty());
+            for i in 0..n {
+                assert_eq!(b.push(Node::new(i)), i);
+            }
+            assert_eq!(b.len(), n);
+
+            for i in 0..n {
+                let j = (i + 1) % n;
+                b.link(i, |node, next| node.next = next, j);
+                b.link(j, |node, prev| node.prev = prev, i);
+            }
+        });
+
+        assert_eq!(vec.len(), n);
+        for i in 0..n {
+            assert_eq!(vec[i].value, i);
+            assert_eq!(vec.index_from_ptr(vec[i].next), Some((i + 1) % n));
+            assert_eq!(vec.index_from_ptr(vec[i].prev), Some((i + n - 1) % n));
+        }
+
+        // pointers remain valid while the vector grows
+        for i in n..(10 * n) {
+            vec.imp_push(Node::new(i));
+        }
+        for i in 0..n {
+            assert_eq!(vec.index_from_ptr(vec[i].next), Some((i + 1) % n));
+        }
+    }
+
+    test(SplitVec::new());
+    test(SplitVec::with_recursive_growth());
+    test(SplitVec::with_linear_growth(3));
+    test(FixedVec::new(1000));
+}
+
+#[test]
+fn build_cyclic_self_loop() {
+    let vec = ImpVec::build_cyclic(SplitVec::new(), |b| {
+        let x = b.push(Node::new(42));
+        b.link(x, |node, next| node.next = next, x);
+    });
+    assert_eq!(vec[0].next, &vec[0] as *const Node);
+}
+
+#[test]
+fn build_cyclic_self_loop_deref() {
+    let vec = ImpVec::build_cyclic(SplitVec::new(), |b| {
+        let x = b.push(Node::new(42));
+        b.link(x, |node, next| node.next = next, x);
+        let y = b.push(Node::new(7));
+        b.link(y, |node, next| node.next = next, x);
+    });
+
+    let node = unsafe { &*vec[0].next };
+    assert_eq!(node.value, 42);
+    let node = unsafe { &*node.next };
+    assert_eq!(node.value, 42);
+    assert_eq!(unsafe { (*vec[1].next).value }, 42);
+}
+
+#[test]
+#[should_panic(expected = "out-of-bounds")]
+fn build_cyclic_link_out_of_bounds() {
+    let _ = ImpVec::build_cyclic(SplitVec::new(), |b| {
+        let x = b.push(Node::new(0));
+        b.link(x, |node, next| node.next = next, x + 1);
+    });
+}
+
+#[test]
+#[should_panic(expected = "cannot push or link from within the `set` closure")]
+fn build_cyclic_link_within_link() {
+    let _ = ImpVec::build_cyclic(SplitVec::new(), |b| {
+        let x = b.push(Node::new(0));
+        let y = b.push(Node::new(1));
+        b.link(
+            x,
+            |node, next| {
+                b.link(x, |other, _| other.value = 42, y);
+                node.next = next;
+            },
+            y,
+        );
+    });
+}
+
+#[test]
+#[should_panic(expected = "cannot push or link from within the `set` closure")]
+fn build_cyclic_push_within_link() {
+    let _ = ImpVec::build_cyclic(SplitVec::new(), |b| {
+        let x = b.push(Node::new(0));
+        b.link(
+            x,
+            |node, next| {
+                b.push(Node::new(1));
+                node.next = next;
+            },
+            x,
+        );
+    });
+}
@@ -13,3 +13,54 @@ fn debug() {
 
     assert_eq!(imp_vec_str, expected_str);
 }
+
+#[test]
+fn debug_shallow() {
+    use std::cell::Cell;
+    use std::fmt::{Debug, Formatter, Result};
+
+    struct Node<'a> {
+        value: usize,
+        next: Cell<Option<&'a Node<'a>>>,
+    }
+
+    impl<'a> Debug for Node<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            // follows the references; and hence, never terminates on a cycle
+            write!(f, "{} -> {:?}", self.value, self.next.get())
+        }
+    }
+
+    struct Ring<'a>(ImpVec<Node<'a>>);
+
+    impl<'a> Debug for Ring<'a> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            self.0.debug_shallow(f)
+        }
+    }
+
+    let ring = Ring(ImpVec::new());
+    let a = ring.0.imp_push_get_ref(Node {
+        value: 0,
+        next: Cell::new(None),
+    });
+    let b = ring.0.imp_push_get_ref(Node {
+        value: 1,
+        next: Cell::new(Some(a)),
+    });
+    let c = ring.0.imp_push_get_ref(Node {
+        value: 2,
+        next: Cell::new(Some(b)),
+    });
+    a.next.set(Some(c));
+
+    let repr = format!("{:?}", ring);
+    let expected = format!(
+        "[0: {:p}, 1: {:p}, 2: {:p}]",
+        &ring.0[0], &ring.0[1], &ring.0[2]
+    );
+    assert_eq!(repr, expected);
+
+    let empty = Ring(ImpVec::new());
+    assert_eq!(format!("{:?}", empty), "[]");
+}
@@ -0,0 +1,370 @@
+use orx_imp_vec::*;
+use std::fmt::Debug;
+
+#[test]
+fn remove_first() {
+    fn test<P: PinnedVec<String>>(mut vec: ImpVec<String, P>) {
+        assert_eq!(vec.remove_first(), None);
+
+        for i in 0..100 {
+            vec.imp_push(i.to_string());
+        }
+
+        for i in 0..100 {
+            assert_eq!(vec.remove_first(), Some(i.to_string()));
+            assert_eq!(vec.len(), 99 - i);
+            if let Some(first) = vec.first() {
+                assert_eq!(first, &(i + 1).to_string());
+            }
+        }
+
+        assert_eq!(vec.remove_first(), None);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(3));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+fn take() {
+    fn test<G: Growth + Default>(mut vec: ImpVec<String, SplitVec<String, G>>) {
+        for i in 0..100 {
+            vec.imp_push(i.to_string());
+        }
+        let first = vec.get_ptr(0).unwrap();
+
+        let taken = vec.take();
+        assert!(vec.is_empty());
+        assert_eq!(taken.len(), 100);
+        assert_eq!(taken.get_ptr(0), Some(first));
+        for i in 0..100 {
+            assert_eq!(taken[i], i.to_string());
+        }
+
+        vec.imp_push("new".to_string());
+        assert_eq!(vec.len(), 1);
+        assert_eq!(taken.len(), 100);
+
+        vec.seal();
+        let taken = vec.take();
+        assert!(taken.is_sealed());
+        assert!(!vec.is_sealed());
+    }
+
+    test(ImpVec::with_doubling_growth());
+    test(ImpVec::with_recursive_growth());
+}
+
+#[test]
+fn replace_range() {
+    fn test<P: PinnedVec<String>>(mut vec: ImpVec<String, P>) {
+        for i in 0..20 {
+            vec.imp_push(i.to_string());
+        }
+        let ptrs: Vec<_> = (0..20).map(|i| vec.get_ptr(i).unwrap()).collect();
+
+        // crosses the fragment boundary at 8
+        vec.replace_range(5..12, (5..12).map(|x| (x * 100).to_string()));
+
+        for i in 0..20 {
+            let expected = match (5..12).contains(&i) {
+                true => (i * 100).to_string(),
+                false => i.to_string(),
+            };
+            assert_eq!(vec[i], expected);
+            assert_eq!(vec.get_ptr(i), Some(ptrs[i]));
+        }
+
+        vec.replace_range(3..3, []);
+        assert_eq!(vec.len(), 20);
+    }
+
+    test(ImpVec::with_linear_growth(3));
+    test(ImpVec::with_doubling_growth());
+    test(ImpVec::with_fixed_capacity(20));
+}
+
+#[test]
+#[should_panic(expected = "number of values must be equal to the length of the range")]
+fn replace_range_count_mismatch() {
+    let mut vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    vec.replace_range(1..3, [10]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn replace_range_out_of_bounds() {
+    let mut vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    vec.replace_range(3..5, [10, 20]);
+}
+
+#[test]
+fn retain_mut() {
+    fn test<P: PinnedVec<String>>(mut vec: ImpVec<String, P>) {
+        vec.retain_mut(|_| true);
+        assert!(vec.is_empty());
+
+        for i in 0..50 {
+            vec.imp_push(i.to_string());
+        }
+
+        vec.retain_mut(|x| {
+            let value: usize = x.parse().expect("number");
+            match value.is_multiple_of(3) {
+                true => {
+                    *x = (value + 1).to_string();
+                    true
+                }
+                false => false,
+            }
+        });
+
+        assert_eq!(vec.len(), 17);
+        for i in 0..17 {
+            assert_eq!(vec[i], (i * 3 + 1).to_string());
+        }
+
+        vec.retain_mut(|_| false);
+        assert!(vec.is_empty());
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(50));
+}
+
+#[test]
+fn copy_from_slice_at() {
+    let mut vec = ImpVec::with_linear_growth(3);
+    for i in 0..20 {
+        vec.imp_push(i);
+    }
+    let ptrs: Vec<_> = (0..20).map(|i| vec.get_ptr(i).unwrap()).collect();
+
+    vec.copy_from_slice_at(1, &[10, 20, 30]);
+    vec.copy_from_slice_at(8, &[80; 8]);
+    vec.copy_from_slice_at(19, &[190]);
+    vec.copy_from_slice_at(5, &[]);
+
+    let mut expected: Vec<_> = (0..20).collect();
+    expected[1..4].copy_from_slice(&[10, 20, 30]);
+    expected[8..16].fill(80);
+    expected[19] = 190;
+    assert_eq!(&vec, expected.as_slice());
+    for (i, ptr) in ptrs.into_iter().enumerate() {
+        assert_eq!(vec.get_ptr(i), Some(ptr));
+    }
+
+    let mut fixed = ImpVec::with_fixed_capacity(10);
+    fixed.imp_extend_from_slice(&[0; 10]);
+    fixed.copy_from_slice_at(2, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(&fixed, [0, 0, 1, 2, 3, 4, 5, 6, 7, 8].as_slice());
+}
+
+#[test]
+#[should_panic(expected = "range must lie within a single fragment")]
+fn copy_from_slice_at_crossing_fragments() {
+    let mut vec = ImpVec::with_linear_growth(2);
+    vec.imp_extend_from_slice(&[0; 8]);
+    vec.copy_from_slice_at(3, &[1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn copy_from_slice_at_out_of_bounds() {
+    let mut vec = ImpVec::with_linear_growth(2);
+    vec.imp_extend_from_slice(&[0; 8]);
+    vec.copy_from_slice_at(7, &[1, 2]);
+}
+
+#[test]
+fn move_element_to() {
+    fn test<P: PinnedVec<String>, Q: PinnedVec<String>>(
+        mut src: ImpVec<String, P>,
+        dst: ImpVec<String, Q>,
+    ) {
+        assert!(!src.move_element_to(0, &dst));
+
+        for i in 0..10 {
+            src.imp_push(i.to_string());
+        }
+        let first_in_dst = dst.imp_push_get_ref("first".to_string());
+
+        assert!(src.move_element_to(3, &dst));
+        assert!(src.move_element_to(0, &dst));
+        assert!(src.move_element_to(7, &dst)); // last
+        assert!(!src.move_element_to(7, &dst));
+
+        let src_values: Vec<_> = (0..src.len()).map(|i| src[i].as_str()).collect();
+        assert_eq!(src_values, ["8", "1", "2", "9", "4", "5", "6"]);
+
+        let dst_values: Vec<_> = (0..dst.len()).map(|i| dst[i].as_str()).collect();
+        assert_eq!(dst_values, ["first", "3", "0", "7"]);
+        assert_eq!(first_in_dst, "first");
+    }
+
+    test(ImpVec::new(), ImpVec::new());
+    test(
+        ImpVec::with_recursive_growth(),
+        ImpVec::with_linear_growth(1),
+    );
+    test(
+        ImpVec::with_fixed_capacity(10),
+        ImpVec::with_fixed_capacity(4),
+    );
+}
+
+#[test]
+fn move_element_to_rejected_by_dst() {
+    let mut src = ImpVec::new();
+    src.imp_extend_from_slice(&[0, 1, 2]);
+
+    let full = ImpVec::with_fixed_capacity(1);
+    full.imp_push(42);
+    assert!(!src.move_element_to(1, &full));
+    assert_eq!(&full, [42].as_slice());
+
+    let sealed = ImpVec::new();
+    sealed.seal();
+    assert!(!src.move_element_to(1, &sealed));
+    assert!(sealed.is_empty());
+
+    assert_eq!(&src, [0, 1, 2].as_slice());
+}
+
+#[test]
+fn remove_indices() {
+    fn test<P: PinnedVec<String> + Debug>(mut vec: ImpVec<String, P>) {
+        assert!(vec.remove_indices(&[0, 1]).is_empty());
+
+        for i in 0..100 {
+            vec.imp_push(i.to_string());
+        }
+
+        let removed = vec.remove_indices(&[]);
+        assert!(removed.is_empty());
+        assert_eq!(vec.len(), 100);
+
+        let removed = vec.remove_indices(&[97, 3, 50, 3, 12, 99, 100, 1000, 4]);
+        assert_eq!(removed, ["3", "4", "12", "50", "97", "99"]);
+
+        let expected: Vec<_> = (0..100)
+            .filter(|i| ![3, 4, 12, 50, 97, 99].contains(i))
+            .map(|i| i.to_string())
+            .collect();
+        assert_eq!(&vec, expected.as_slice());
+
+        // still appendable
+        vec.imp_push("x".to_string());
+        assert_eq!(vec.len(), 95);
+        assert_eq!(&vec[94], "x");
+
+        let removed = vec.remove_indices(&(0..95).collect::<Vec<_>>());
+        assert_eq!(removed.len(), 95);
+        assert!(vec.is_empty());
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+fn swap_remove_get() {
+    fn test<P: PinnedVec<String>>(mut vec: ImpVec<String, P>) {
+        assert_eq!(vec.swap_remove_get(0), None);
+
+        for i in 0..10 {
+            vec.imp_push(i.to_string());
+        }
+
+        let (removed, occupant) = vec.swap_remove_get(2).expect("in bounds");
+        assert_eq!(removed, "2");
+        let occupant = occupant.expect("not the last");
+        assert_eq!(occupant, "9");
+        let occupant_ptr = occupant as *const String;
+        assert_eq!(occupant_ptr, &vec[2] as *const String);
+
+        let (removed, occupant) = vec.swap_remove_get(8).expect("in bounds");
+        assert_eq!(removed, "8");
+        assert!(occupant.is_none());
+
+        assert_eq!(vec.swap_remove_get(8), None);
+
+        let (removed, occupant) = vec.swap_remove_get(0).expect("in bounds");
+        assert_eq!(removed, "0");
+        assert_eq!(occupant.map(|x| x.as_str()), Some("7"));
+
+        let values: Vec<_> = (0..vec.len()).map(|i| vec[i].as_str()).collect();
+        assert_eq!(values, ["7", "1", "9", "3", "4", "5", "6"]);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(10));
+}
+
+#[test]
+fn apply_permutation() {
+    fn test<P: PinnedVec<String> + Debug>(mut vec: ImpVec<String, P>) {
+        vec.apply_permutation(&[]);
+        assert!(vec.is_empty());
+
+        for i in 0..50 {
+            vec.imp_push((i * 17 % 53).to_string());
+        }
+
+        let perm = vec.argsort();
+        vec.apply_permutation(&perm);
+
+        let mut expected: Vec<_> = (0..50).map(|i| (i * 17 % 53).to_string()).collect();
+        expected.sort();
+        assert_eq!(&vec, expected.as_slice());
+        assert!(vec.argsort().into_iter().eq(0..50));
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(50));
+}
+
+#[test]
+fn apply_permutation_cycles() {
+    let mut vec = ImpVec::new();
+    vec.imp_extend_from_slice(&['a', 'b', 'c', 'd', 'e', 'f']);
+
+    vec.apply_permutation(&[2, 0, 1, 3, 5, 4]);
+    assert_eq!(&vec, ['c', 'a', 'b', 'd', 'f', 'e'].as_slice());
+}
+
+#[test]
+#[should_panic(expected = "perm is not a permutation of 0..4")]
+fn apply_permutation_duplicate() {
+    let mut vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    vec.apply_permutation(&[0, 1, 1, 3]);
+}
+
+#[test]
+#[should_panic(expected = "perm is not a permutation of 0..4")]
+fn apply_permutation_out_of_bounds() {
+    let mut vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    vec.apply_permutation(&[0, 1, 4, 3]);
+}
+
+#[test]
+#[should_panic(expected = "cannot be applied to a vector of length 4")]
+fn apply_permutation_length_mismatch() {
+    let mut vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    vec.apply_permutation(&[0, 1, 2]);
+}
@@ -0,0 +1,84 @@
+use orx_imp_vec::*;
+use std::fmt::Debug;
+
+#[test]
+fn into_editable_into_appendable() {
+    fn test<P: PinnedVec<String> + Debug>(vec: ImpVec<String, P>) {
+        for i in 0..10 {
+            vec.imp_push(i.to_string());
+        }
+
+        let mut vec = vec.into_editable();
+        assert_eq!(vec.len(), 10);
+
+        vec.insert(0, 42.to_string());
+        assert_eq!(vec.remove(5), 4.to_string());
+        assert_eq!(vec.pop(), Some(9.to_string()));
+        vec.truncate(6);
+        assert_eq!(vec.len(), 6);
+
+        let vec = vec.into_appendable();
+        let first = vec.imp_push_get_ref(0.to_string());
+        vec.imp_push(1.to_string());
+        assert_eq!(first, "0");
+
+        let expected = ["42", "0", "1", "2", "3", "5", "0", "1"].map(|x| x.to_string());
+        assert_eq!(&vec, expected.as_slice());
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(11));
+}
+
+#[test]
+fn editable_into_inner() {
+    let vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[1, 2, 3]);
+
+    let mut vec = vec.into_editable();
+    vec.swap(0, 2);
+
+    let pinned: SplitVec<i32> = vec.into_inner();
+    assert_eq!(&pinned, &[3, 2, 1]);
+}
+
+#[test]
+fn editable_editing_methods() {
+    fn test<P: PinnedVec<i32> + Debug>(vec: ImpVec<i32, P>) {
+        vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let mut vec = vec.into_editable();
+
+        assert_eq!(vec.remove_first(), Some(0));
+        vec.retain_mut(|x| match *x % 3 == 0 {
+            true => false,
+            false => {
+                *x *= 10;
+                true
+            }
+        });
+        assert_eq!(
+            vec.iter().copied().collect::<Vec<_>>(),
+            [10, 20, 40, 50, 70, 80]
+        );
+
+        assert_eq!(vec.swap_remove_get(0), Some((10, Some(&80))));
+        assert_eq!(vec.remove_indices(&[4, 0]), [80, 70]);
+        assert_eq!(vec.iter().copied().collect::<Vec<_>>(), [20, 40, 50]);
+
+        vec.apply_permutation(&[2, 0, 1]);
+        vec.replace_range(1..3, [21, 41]);
+        vec.copy_from_slice_at(0, &[51]);
+        if let Some(last) = vec.last_mut() {
+            *last += 1;
+        }
+        assert_eq!(vec.get_slice_mut(0..0), Some(&mut [][..]));
+        assert_eq!(vec.iter().copied().collect::<Vec<_>>(), [51, 21, 42]);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(4));
+    test(ImpVec::with_fixed_capacity(10));
+}
@@ -29,3 +29,26 @@ fn eq() {
     assert_eq!(&vec1, other.as_slice());
     assert_eq!(other.as_slice(), &vec1);
 }
+
+#[test]
+fn eq_self_does_not_compare_elements() {
+    struct PanickingEq(usize);
+    impl PartialEq for PanickingEq {
+        fn eq(&self, _: &Self) -> bool {
+            panic!("elements must not be compared")
+        }
+    }
+
+    let vec: ImpVec<_> = (0..100).map(PanickingEq).collect();
+    let alias = &vec;
+    assert!(vec.eq(alias));
+    assert!(alias.eq(&vec));
+    assert_eq!(vec[42].0, 42);
+
+    let fixed: ImpVec<_, FixedVec<_>> = (0..100).map(PanickingEq).collect();
+    let alias = &fixed;
+    assert!(fixed.eq(alias));
+
+    let empty: ImpVec<PanickingEq> = ImpVec::new();
+    assert!(empty.ne(&vec));
+}
@@ -0,0 +1,86 @@
+use orx_imp_vec::*;
+
+#[test]
+fn capacity_exceeded() {
+    let vec = ImpVec::with_fixed_capacity(3);
+    for i in 0..3 {
+        assert_eq!(vec.try_imp_push(i), Ok(()));
+    }
+
+    let error = vec.try_imp_push(3).unwrap_err();
+    assert_eq!(
+        error,
+        ImpVecError::CapacityExceeded {
+            capacity: 3,
+            needed: 4
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "capacity exceeded: the fixed capacity is 3 but 4 elements are needed"
+    );
+    assert_eq!(&vec, [0, 1, 2].as_slice());
+
+    // dynamic capacity never exceeds
+    let vec = ImpVec::with_linear_growth(1);
+    for i in 0..100 {
+        assert_eq!(vec.try_imp_push(i), Ok(()));
+    }
+    assert_eq!(vec.len(), 100);
+}
+
+#[test]
+fn index_out_of_bounds() {
+    fn min_of_first_and_third(vec: &ImpVec<char>) -> Result<char, ImpVecError> {
+        let a = vec.try_index(0)?;
+        let b = vec.try_index(2)?;
+        Ok(if a < b { *a } else { *b })
+    }
+
+    let vec = ImpVec::new();
+    vec.imp_extend_from_slice(&['x', 'y']);
+
+    let error = min_of_first_and_third(&vec).unwrap_err();
+    assert_eq!(error, ImpVecError::IndexOutOfBounds { index: 2, len: 2 });
+    assert_eq!(
+        error.to_string(),
+        "index out of bounds: the len is 2 but the index is 2"
+    );
+
+    vec.imp_push('a');
+    assert_eq!(min_of_first_and_third(&vec), Ok('a'));
+}
+
+#[test]
+fn sealed() {
+    fn test<P: PinnedVec<String>>(vec: ImpVec<String, P>) {
+        assert_eq!(vec.try_imp_push("a".to_string()), Ok(()));
+        vec.seal();
+
+        let error = vec.try_imp_push("b".to_string()).unwrap_err();
+        assert_eq!(error, ImpVecError::Sealed);
+        assert_eq!(error.to_string(), "cannot push to a sealed imp-vec");
+        assert_eq!(vec.len(), 1);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(1));
+}
+
+#[test]
+fn error_trait() {
+    fn describe(error: &dyn std::error::Error) -> String {
+        error.to_string()
+    }
+
+    assert_eq!(
+        describe(&ImpVecError::Sealed),
+        "cannot push to a sealed imp-vec"
+    );
+    assert_eq!(
+        describe(&IndexError { index: 1, len: 0 }),
+        "index out of bounds: the len is 0 but the index is 1"
+    );
+}
@@ -0,0 +1,45 @@
+use orx_imp_vec::*;
+
+#[test]
+fn fingerprint_independent_of_backing() {
+    fn fill<P: PinnedVec<String>>(vec: ImpVec<String, P>, n: usize) -> u64 {
+        for i in 0..n {
+            vec.imp_push(i.to_string());
+        }
+        vec.fingerprint()
+    }
+
+    for n in [0, 1, 4, 5, 100, 1000] {
+        let expected = fill(ImpVec::new(), n);
+        assert_eq!(fill(ImpVec::with_doubling_growth(), n), expected);
+        assert_eq!(fill(ImpVec::with_recursive_growth(), n), expected);
+        assert_eq!(fill(ImpVec::with_linear_growth(3), n), expected);
+        assert_eq!(fill(ImpVec::with_fixed_capacity(n), n), expected);
+    }
+}
+
+#[test]
+fn fingerprint_reproducible() {
+    let vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[1u32, 2, 3]);
+    let first = vec.fingerprint();
+    assert_eq!(vec.fingerprint(), first);
+
+    // fixed seed: independent of the process and run
+    let empty = ImpVec::<u32>::new();
+    assert_eq!(empty.fingerprint(), 0xa8c7f832281a39c5);
+}
+
+#[test]
+fn fingerprint_sensitive_to_contents() {
+    let vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[1, 2, 3]);
+
+    let reordered = ImpVec::new();
+    reordered.imp_extend_from_slice(&[1, 3, 2]);
+    assert_ne!(vec.fingerprint(), reordered.fingerprint());
+
+    let longer = ImpVec::new();
+    longer.imp_extend_from_slice(&[1, 2, 3, 0]);
+    assert_ne!(vec.fingerprint(), longer.fingerprint());
+}
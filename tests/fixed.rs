@@ -0,0 +1,53 @@
+use orx_imp_vec::*;
+
+#[test]
+fn fixed_imp_vec_fill() {
+    let vec = FixedImpVec::<String, 5>::new();
+    assert_eq!(FixedImpVec::<String, 5>::CAPACITY, 5);
+    assert_eq!(vec.capacity(), 5);
+    assert!(vec.is_empty());
+    assert!(!vec.is_full());
+
+    let first = vec.imp_push_get_ref(0.to_string());
+    for i in 1..5 {
+        assert!(!vec.is_full());
+        assert_eq!(vec.try_imp_push(i.to_string()), Ok(()));
+    }
+    assert!(vec.is_full());
+    assert_eq!(vec.len(), 5);
+    assert_eq!(first, "0");
+
+    assert_eq!(
+        vec.try_imp_push(5.to_string()),
+        Err(ImpVecError::CapacityExceeded {
+            capacity: 5,
+            needed: 6
+        })
+    );
+    assert_eq!(vec.len(), 5);
+
+    for i in 0..5 {
+        assert_eq!(vec[i], i.to_string());
+    }
+}
+
+#[test]
+fn fixed_imp_vec_index_mut() {
+    let mut vec: FixedImpVec<i32, 3> = Default::default();
+    vec.imp_extend_from_slice(&[0, 1, 2]);
+
+    vec[1] = 42;
+    assert_eq!(&*vec, [0, 42, 2].as_slice());
+
+    let vec = vec.into_inner();
+    assert_eq!(vec.capacity(), 3);
+    assert_eq!(&vec, [0, 42, 2].as_slice());
+}
+
+#[test]
+#[should_panic]
+fn fixed_imp_vec_push_over_capacity() {
+    let vec = FixedImpVec::<i32, 2>::new();
+    vec.imp_extend_from_slice(&[0, 1]);
+    vec.imp_push(2);
+}
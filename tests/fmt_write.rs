@@ -0,0 +1,37 @@
+use core::fmt::Write;
+use orx_imp_vec::*;
+
+#[test]
+fn fmt_write() {
+    fn test<P: PinnedVec<char>>(vec: ImpVec<char, P>) {
+        let mut expected = String::new();
+        for i in 0..100 {
+            write!(&vec, "{}→{};", i, i * 2).unwrap();
+            write!(&mut expected, "{}→{};", i, i * 2).unwrap();
+        }
+
+        let first = &vec[0];
+        writeln!(&vec, "done").unwrap();
+        expected.push_str("done\n");
+
+        assert_eq!(first, &'0');
+        assert_eq!(vec.len(), expected.chars().count());
+        for (i, c) in expected.chars().enumerate() {
+            assert_eq!(vec[i], c);
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(3));
+    test(ImpVec::with_fixed_capacity(2000));
+}
+
+#[test]
+fn fmt_write_sealed() {
+    let vec = ImpVec::new();
+    write!(&vec, "abc").unwrap();
+    vec.seal();
+    assert!(write!(&vec, "def").is_err());
+    assert_eq!(&vec, ['a', 'b', 'c'].as_slice());
+}
@@ -0,0 +1,550 @@
+use orx_imp_vec::*;
+
+#[test]
+fn iter_with_fragment_marks() {
+    fn test<G: Growth>(vec: ImpVec<usize, SplitVec<usize, G>>) {
+        for i in 0..1000 {
+            vec.imp_push(i);
+        }
+
+        let num_marks = vec.iter_with_fragment_marks().filter(|x| x.0).count();
+        assert_eq!(num_marks, vec.fragments().len());
+
+        for (i, (_, x)) in vec.iter_with_fragment_marks().enumerate() {
+            assert_eq!(i, *x);
+        }
+    }
+
+    test(ImpVec::with_doubling_growth());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(4));
+}
+
+#[test]
+fn iter_with_fragment_marks_fixed() {
+    let vec = ImpVec::with_fixed_capacity(100);
+    assert_eq!(vec.iter_with_fragment_marks().count(), 0);
+
+    for i in 0..100 {
+        vec.imp_push(i);
+    }
+    let marks: Vec<_> = vec.iter_with_fragment_marks().map(|x| x.0).collect();
+    assert!(marks[0]);
+    assert!(marks.iter().skip(1).all(|x| !x));
+}
+
+#[test]
+fn fragments_iter() {
+    let vec = ImpVec::with_doubling_growth();
+    assert_eq!(vec.fragments_iter().count(), 0);
+
+    for i in 0..100 {
+        vec.imp_push(i);
+    }
+
+    assert_eq!(vec.fragments_iter().count(), vec.fragments().len());
+    let flattened: Vec<_> = vec
+        .fragments_iter()
+        .flat_map(|x| x.iter())
+        .copied()
+        .collect();
+    assert_eq!(&vec, &flattened);
+}
+
+#[test]
+fn fragments_iter_snapshot() {
+    fn snapshot<P: PinnedVec<usize>>(vec: ImpVec<usize, P>) -> Vec<Vec<usize>> {
+        for i in 0..100 {
+            vec.imp_push(i);
+        }
+        vec.fragments_iter().map(|x| x.to_vec()).collect()
+    }
+
+    fn lengths(fragments: &[Vec<usize>]) -> Vec<usize> {
+        fragments.iter().map(|x| x.len()).collect()
+    }
+
+    fn assert_logical_order(fragments: &[Vec<usize>]) {
+        let flattened: Vec<_> = fragments.iter().flatten().copied().collect();
+        assert_eq!(flattened, (0..100).collect::<Vec<_>>());
+    }
+
+    let doubling = snapshot(ImpVec::with_doubling_growth());
+    assert_eq!(lengths(&doubling), [4, 8, 16, 32, 40]);
+    assert_logical_order(&doubling);
+
+    let recursive = snapshot(ImpVec::with_recursive_growth());
+    assert_eq!(lengths(&recursive), [4, 8, 16, 32, 40]);
+    assert_logical_order(&recursive);
+
+    let linear = snapshot(ImpVec::with_linear_growth(3));
+    assert_eq!(lengths(&linear), [8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 4]);
+    assert_logical_order(&linear);
+
+    let fixed = snapshot(ImpVec::with_fixed_capacity(128));
+    assert_eq!(lengths(&fixed), [100]);
+    assert_logical_order(&fixed);
+}
+
+#[test]
+fn fragments_iter_mut() {
+    fn test<P: PinnedVec<usize>>(mut vec: ImpVec<usize, P>) {
+        assert_eq!(vec.fragments_iter_mut().count(), 0);
+
+        for i in 0..100 {
+            vec.imp_push(i);
+        }
+        let num_fragments = vec.fragments_iter().count();
+        assert_eq!(vec.fragments_iter_mut().count(), num_fragments);
+
+        for (f, fragment) in vec.fragments_iter_mut().enumerate() {
+            for x in fragment.iter_mut() {
+                *x = *x * 1000 + f;
+            }
+        }
+
+        let mut begin = 0;
+        for (f, fragment) in vec.fragments_iter().enumerate() {
+            for (i, x) in fragment.iter().enumerate() {
+                assert_eq!(*x, (begin + i) * 1000 + f);
+            }
+            begin += fragment.len();
+        }
+        assert_eq!(begin, 100);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(3));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+fn fragments_iter_rev() {
+    let vec = ImpVec::with_linear_growth(3);
+    assert_eq!(vec.fragments_iter_rev().count(), 0);
+
+    for i in 0..100 {
+        vec.imp_push(i);
+    }
+
+    let fragments: Vec<_> = vec.fragments_iter().collect();
+    let mut fragments_rev: Vec<_> = vec.fragments_iter_rev().collect();
+    assert_eq!(fragments_rev.len(), fragments.len());
+    assert_eq!(fragments_rev[0], vec.fragments().last().unwrap().as_slice());
+
+    fragments_rev.reverse();
+    let flattened: Vec<_> = fragments_rev
+        .iter()
+        .flat_map(|x| x.iter())
+        .copied()
+        .collect();
+    assert_eq!(&vec, &flattened);
+
+    let reversed: Vec<_> = vec
+        .fragments_iter_rev()
+        .flat_map(|x| x.iter().rev())
+        .copied()
+        .collect();
+    let expected: Vec<_> = (0..100).rev().collect();
+    assert_eq!(reversed, expected);
+}
+
+#[test]
+fn fragments_iter_fixed() {
+    let vec = ImpVec::with_fixed_capacity(10);
+    assert_eq!(vec.fragments_iter().count(), 0);
+    assert_eq!(vec.fragments_iter_rev().count(), 0);
+
+    vec.imp_extend_from_slice(&[0, 1, 2]);
+    let fragments: Vec<_> = vec.fragments_iter().collect();
+    assert_eq!(fragments, [&[0, 1, 2][..]]);
+    let fragments: Vec<_> = vec.fragments_iter_rev().collect();
+    assert_eq!(fragments, [&[0, 1, 2][..]]);
+}
+
+#[test]
+fn map_preserving_layout() {
+    fn test<G: Growth>(vec: ImpVec<usize, SplitVec<usize, G>>) {
+        let mapped = vec.map_preserving_layout(|x| x.to_string());
+        assert!(mapped.is_empty());
+
+        for i in 0..500 {
+            vec.imp_push(i);
+        }
+
+        let mapped = vec.map_preserving_layout(|x| x.to_string());
+        assert_eq!(mapped.len(), vec.len());
+        assert_eq!(mapped.fragments().len(), vec.fragments().len());
+        for (a, b) in vec.fragments().iter().zip(mapped.fragments()) {
+            assert_eq!(a.len(), b.len());
+            assert_eq!(a.capacity(), b.capacity());
+        }
+        for i in 0..mapped.len() {
+            assert_eq!(mapped[i], i.to_string());
+        }
+    }
+
+    test(ImpVec::with_doubling_growth());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(5));
+}
+
+#[test]
+fn compact() {
+    let mut vec = ImpVec::with_doubling_growth();
+    for i in 0..1000 {
+        vec.imp_push(i.to_string());
+    }
+    assert!(vec.fragments().len() > 1);
+
+    let compact = vec.compact();
+    assert!(vec.is_empty());
+    assert_eq!(compact.fragments().len(), 1);
+
+    let fragments: Vec<_> = compact.fragments_iter().collect();
+    assert_eq!(fragments.len(), 1);
+    let expected: Vec<_> = (0..1000).map(|x| x.to_string()).collect();
+    assert_eq!(fragments[0], expected.as_slice());
+
+    vec.imp_push(42.to_string());
+    assert_eq!(&vec, [42.to_string()].as_slice());
+
+    let mut empty = ImpVec::<String>::new();
+    let compact = empty.compact();
+    assert!(compact.is_empty());
+    compact.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5].map(|x| x.to_string()));
+    assert_eq!(compact.len(), 6);
+}
+
+#[test]
+fn layout_report() {
+    let vec = ImpVec::with_doubling_growth();
+    assert_eq!(vec.layout_report(), "1 fragment: [cap=4 len=0]");
+
+    for i in 0..4 {
+        vec.imp_push(i);
+    }
+    assert_eq!(vec.layout_report(), "1 fragment: [cap=4 len=4]");
+
+    for i in 4..15 {
+        vec.imp_push(i);
+    }
+    assert_eq!(
+        vec.layout_report(),
+        "3 fragments: [cap=4 len=4][cap=8 len=8][cap=16 len=3]"
+    );
+
+    let vec = ImpVec::with_linear_growth(3);
+    vec.imp_extend_from_slice(&[0; 20]);
+    assert_eq!(
+        vec.layout_report(),
+        "3 fragments: [cap=8 len=8][cap=8 len=8][cap=8 len=4]"
+    );
+}
+
+#[test]
+fn set_next_fragment_capacity() {
+    let vec = ImpVec::with_recursive_growth();
+
+    // when the last fragment is empty, it is replaced on the next push
+    vec.set_next_fragment_capacity(10);
+    assert_eq!(vec.capacity(), 4);
+    vec.imp_push(0);
+    assert_eq!(vec.fragments().len(), 1);
+    assert_eq!(vec.capacity(), 10);
+
+    for i in 1..10 {
+        vec.imp_push(i);
+    }
+    let first = &vec[0];
+
+    // when the last fragment is full
+    vec.set_next_fragment_capacity(1000);
+    assert_eq!(vec.fragments().len(), 1);
+    vec.imp_push(10);
+    assert_eq!(vec.fragments().len(), 2);
+    assert_eq!(vec.fragments()[1].capacity(), 1000);
+
+    // when the last fragment is partially filled, it is filled up first
+    vec.set_next_fragment_capacity(3);
+    vec.imp_extend_from_slice(&(11..1010).collect::<Vec<_>>());
+    assert_eq!(vec.fragments().len(), 2);
+    vec.imp_extend_from_slice(&[1010, 1011, 1012]);
+    assert_eq!(vec.fragments().len(), 3);
+    assert_eq!(vec.fragments()[1].len(), 1000);
+    assert_eq!(vec.fragments()[2].capacity(), 3);
+    assert_eq!(vec.fragments()[2].len(), 3);
+
+    // reverts to the growth strategy
+    assert_eq!(vec.fragments().len(), 3);
+    vec.imp_push(1013);
+    assert_eq!(vec.fragments().len(), 4);
+    assert_eq!(vec.fragments()[3].capacity(), 6);
+
+    assert_eq!(first, &0);
+    assert_eq!(vec.len(), 1014);
+    for i in 0..1014 {
+        assert_eq!(vec[i], i);
+    }
+}
+
+#[test]
+fn set_next_fragment_capacity_overrides_pending() {
+    let vec = ImpVec::with_recursive_growth();
+    vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+
+    vec.set_next_fragment_capacity(100);
+    vec.set_next_fragment_capacity(7);
+    vec.imp_push(4);
+    assert_eq!(vec.fragments()[1].capacity(), 7);
+}
+
+#[test]
+fn set_next_fragment_capacity_on_grow() {
+    use std::sync::{Arc, Mutex};
+
+    let log = Arc::new(Mutex::new(vec![]));
+    let mut vec = ImpVec::with_recursive_growth();
+    let cloned = log.clone();
+    vec.on_grow(Box::new(move |capacity| {
+        cloned.lock().unwrap().push(capacity)
+    }));
+
+    vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    vec.set_next_fragment_capacity(10);
+    assert!(log.lock().unwrap().is_empty());
+
+    vec.imp_extend_from_slice(&[4, 5]);
+    assert_eq!(log.lock().unwrap().as_slice(), &[14]);
+}
+
+#[test]
+#[should_panic(expected = "cannot push to a sealed imp-vec")]
+fn set_next_fragment_capacity_sealed() {
+    let vec = ImpVec::<char, _>::with_recursive_growth();
+    vec.seal();
+    vec.set_next_fragment_capacity(10);
+}
+
+#[test]
+#[should_panic(expected = "fragment capacity must be positive")]
+fn set_next_fragment_capacity_zero() {
+    let vec = ImpVec::<char, _>::with_recursive_growth();
+    vec.set_next_fragment_capacity(0);
+}
+
+#[test]
+fn imp_extend_reserving() {
+    let vec = ImpVec::with_recursive_growth();
+    vec.imp_push(0);
+    let first = &vec[0];
+
+    // room in the last fragment suffices
+    vec.imp_extend_reserving(1..4);
+    assert_eq!(vec.fragments().len(), 1);
+
+    // a single fragment is allocated for the exact size hint
+    vec.imp_extend_reserving(4..1004);
+    assert_eq!(vec.fragments().len(), 2);
+    assert_eq!(vec.fragments()[1].capacity(), 1000);
+    assert_eq!(vec.fragments()[1].len(), 1000);
+
+    // without a lower bound, growth strategy is used
+    vec.imp_extend_reserving((1004..1010).filter(|_| true));
+    assert_eq!(vec.fragments().len(), 3);
+    assert_eq!(vec.fragments()[2].capacity(), 2000);
+
+    assert_eq!(first, &0);
+    assert_eq!(vec.len(), 1010);
+    for i in 0..1010 {
+        assert_eq!(vec[i], i);
+    }
+}
+
+#[test]
+fn imp_extend_reserving_partially_filled() {
+    let vec = ImpVec::with_recursive_growth();
+    vec.imp_extend_from_slice(&[0, 1, 2, 3, 4]);
+    assert_eq!(
+        vec.layout_report(),
+        "2 fragments: [cap=4 len=4][cap=8 len=1]"
+    );
+
+    // only the room of the last fragment counts
+    vec.imp_extend_reserving(5..20);
+    assert_eq!(
+        vec.layout_report(),
+        "3 fragments: [cap=4 len=4][cap=8 len=8][cap=8 len=8]"
+    );
+
+    // room left in a fragment before a grafted one is not available
+    let mut fragment = Vec::with_capacity(10);
+    fragment.push(20);
+    vec.imp_push_fragment(fragment);
+    vec.imp_push_fragment(vec![21]);
+    vec.imp_extend_reserving(22..26);
+    assert_eq!(vec.fragments().len(), 6);
+    assert_eq!(vec.fragments()[5].capacity(), 4);
+    assert_eq!(vec.fragments()[5].len(), 4);
+
+    for i in 0..26 {
+        assert_eq!(vec[i], i);
+    }
+}
+
+#[test]
+fn imp_extend_reserving_sealed() {
+    let vec = ImpVec::with_recursive_growth();
+    vec.imp_extend_from_slice(&[0, 1, 2, 3]);
+    vec.seal();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        vec.imp_extend_reserving(4..100)
+    }));
+    assert!(result.is_err());
+    assert_eq!(vec.layout_report(), "1 fragment: [cap=4 len=4]");
+}
+
+#[test]
+fn first_fragment_capacity() {
+    for exponent in 1..16 {
+        let vec: ImpVec<usize, _> = ImpVec::with_linear_growth(exponent);
+        assert_eq!(vec.first_fragment_capacity(), Some(1 << exponent));
+
+        vec.imp_extend_from_slice(&vec![0; 3 * (1 << exponent)]);
+        assert_eq!(vec.fragments().len(), 3);
+        assert_eq!(vec.first_fragment_capacity(), Some(1 << exponent));
+    }
+
+    let vec: ImpVec<usize, _> = ImpVec::with_doubling_growth();
+    assert_eq!(vec.first_fragment_capacity(), Some(4));
+
+    let vec: ImpVec<usize, _> = ImpVecBuilder::new()
+        .recursive()
+        .first_fragment_capacity(42)
+        .build();
+    assert_eq!(vec.first_fragment_capacity(), Some(42));
+
+    let mut vec = ImpVec::with_recursive_growth();
+    vec.imp_push(0);
+    let _ = vec.compact();
+    assert_eq!(vec.first_fragment_capacity(), Some(4));
+}
+
+#[test]
+fn fragment_and_inner_index() {
+    let vec = ImpVec::with_linear_growth(3);
+    assert_eq!(vec.fragment_and_inner_index(0), None);
+
+    for i in 0..20 {
+        vec.imp_push(i);
+    }
+
+    for i in 0..20 {
+        let (f, j) = vec.fragment_and_inner_index(i).expect("in bounds");
+        assert_eq!((f, j), (i / 8, i % 8));
+        assert_eq!(vec.fragments()[f][j], i);
+    }
+    assert_eq!(vec.fragment_and_inner_index(20), None);
+
+    let vec = ImpVec::with_doubling_growth();
+    vec.imp_extend_from_slice(&[0; 13]);
+    assert_eq!(vec.fragment_and_inner_index(3), Some((0, 3)));
+    assert_eq!(vec.fragment_and_inner_index(4), Some((1, 0)));
+    assert_eq!(vec.fragment_and_inner_index(11), Some((1, 7)));
+    assert_eq!(vec.fragment_and_inner_index(12), Some((2, 0)));
+    assert_eq!(vec.fragment_and_inner_index(13), None);
+
+    let vec = ImpVec::with_recursive_growth();
+    vec.imp_extend_from_slice(&[0, 1, 2]);
+    vec.set_next_fragment_capacity(2);
+    vec.imp_extend_from_slice(&[3, 4, 5]);
+    assert_eq!(vec.fragment_and_inner_index(2), Some((0, 2)));
+    assert_eq!(vec.fragment_and_inner_index(3), Some((0, 3)));
+    assert_eq!(vec.fragment_and_inner_index(4), Some((1, 0)));
+    assert_eq!(vec.fragment_and_inner_index(5), Some((1, 1)));
+    assert_eq!(vec.fragment_and_inner_index(6), None);
+}
+
+#[test]
+fn imp_push_fragment() {
+    let vec = ImpVec::with_recursive_growth();
+    vec.imp_push_fragment(vec![]);
+    assert!(vec.is_empty());
+
+    vec.imp_push_fragment(vec![0, 1, 2]);
+    assert_eq!(vec.fragments().len(), 1);
+    let first = &vec[0];
+
+    vec.imp_extend_from_slice(&[3, 4]);
+    let fourth = &vec[4];
+
+    let mut fragment = Vec::with_capacity(10);
+    fragment.extend(5..10);
+    let ptr = fragment.as_ptr();
+    vec.imp_push_fragment(fragment);
+    assert_eq!(&vec[5] as *const i32, ptr);
+
+    // spare capacity of the grafted fragment is used first
+    vec.imp_extend_from_slice(&[10, 11, 12, 13, 14]);
+    assert_eq!(vec.fragments().last().map(|x| x.len()), Some(10));
+    let num_fragments = vec.fragments().len();
+
+    vec.imp_push(15);
+    assert_eq!(vec.fragments().len(), num_fragments + 1);
+    assert_eq!(vec.fragments().last().map(|x| x.capacity()), Some(20));
+
+    assert_eq!(first, &0);
+    assert_eq!(fourth, &4);
+    assert_eq!(vec.len(), 16);
+    for i in 0..16 {
+        assert_eq!(vec[i], i as i32);
+    }
+}
+
+#[test]
+#[should_panic(expected = "cannot push to a sealed imp-vec")]
+fn imp_push_fragment_sealed() {
+    let vec = ImpVec::with_recursive_growth();
+    vec.imp_push(0);
+    vec.seal();
+    vec.imp_push_fragment(vec![1]);
+}
+
+#[test]
+fn split_slice() {
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..10 {
+        vec.imp_push(i);
+    }
+
+    match vec.split_slice(4..7) {
+        SplitVecSlice::Ok(slice) => {
+            vec.imp_push(10);
+            assert_eq!(slice.iter().sum::<i32>(), 4 + 5 + 6);
+        }
+        _ => panic!("must be within a single fragment"),
+    }
+
+    // cross-fragment
+    let range = 2..9;
+    match vec.split_slice(range.clone()) {
+        SplitVecSlice::Fragmented(first, last) => {
+            assert_eq!((first, last), (0, 2));
+            let values: Vec<_> = vec.fragments()[first..=last]
+                .iter()
+                .flat_map(|x| x.iter())
+                .copied()
+                .skip(range.start - 4 * first)
+                .take(range.len())
+                .collect();
+            assert_eq!(values, [2, 3, 4, 5, 6, 7, 8]);
+        }
+        _ => panic!("must span multiple fragments"),
+    }
+
+    assert_eq!(vec.split_slice(8..12), SplitVecSlice::OutOfBounds);
+    assert_eq!(vec.split_slice(11..12), SplitVecSlice::OutOfBounds);
+    assert_eq!(vec.split_slice(11..11), SplitVecSlice::Ok(&[]));
+}
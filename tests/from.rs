@@ -1,4 +1,5 @@
 use orx_imp_vec::*;
+use std::collections::VecDeque;
 
 #[test]
 fn from() {
@@ -8,3 +9,19 @@ fn from() {
     let imp_vec = ImpVec::from(vec);
     assert_eq!(*imp_vec, &['a', 'b', 'c']);
 }
+
+#[test]
+fn into_vec_deque() {
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..20 {
+        vec.imp_push(i.to_string());
+    }
+
+    let mut deque: VecDeque<_> = vec.into();
+    assert_eq!(deque.len(), 20);
+
+    for i in 0..20 {
+        assert_eq!(deque.pop_front(), Some(i.to_string()));
+    }
+    assert!(deque.is_empty());
+}
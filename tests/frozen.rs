@@ -0,0 +1,51 @@
+use orx_imp_vec::*;
+
+struct Node {
+    value: usize,
+    prev: Option<*const Node>,
+}
+
+#[test]
+fn into_frozen_refs() {
+    fn test<P: PinnedVec<Node>>(vec: ImpVec<Node, P>) {
+        let mut addresses = vec![];
+        let mut prev = None;
+        for value in 0..100 {
+            let node = vec.imp_push_get_ref(Node { value, prev });
+            addresses.push(node as *const Node);
+            prev = Some(node as *const Node);
+        }
+
+        let frozen = vec.into_frozen_refs();
+        assert_eq!(frozen.len(), 100);
+        assert!(!frozen.is_empty());
+        assert!(frozen.get(100).is_none());
+
+        for (i, (node, address)) in frozen.iter().zip(&addresses).enumerate() {
+            assert_eq!(node as *const Node, *address);
+            assert_eq!(&frozen[i] as *const Node, *address);
+            assert_eq!(frozen.get(i).map(|x| x.value), Some(i));
+
+            match node.prev {
+                None => assert_eq!(i, 0),
+                Some(prev) => {
+                    assert_eq!(prev, addresses[i - 1]);
+                    assert_eq!(unsafe { &*prev }.value, i - 1);
+                }
+            }
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+fn into_frozen_refs_empty() {
+    let vec: ImpVec<Node> = ImpVec::new();
+    let frozen = vec.into_frozen_refs();
+    assert!(frozen.is_empty());
+    assert_eq!(frozen.iter().count(), 0);
+}
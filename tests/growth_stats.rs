@@ -0,0 +1,56 @@
+use orx_imp_vec::*;
+
+#[test]
+fn growth_stats_doubling() {
+    let vec = ImpVec::with_doubling_growth();
+    let stats = vec.growth_stats();
+    assert_eq!(stats.fragment_count, 1);
+    assert_eq!(stats.total_capacity, 4);
+    assert_eq!(stats.total_len, 0);
+    assert_eq!(stats.largest_fragment_capacity, 4);
+
+    for i in 0..20 {
+        vec.imp_push(i);
+    }
+    assert_eq!(
+        vec.growth_stats(),
+        GrowthStats {
+            fragment_count: 3,
+            total_capacity: 4 + 8 + 16,
+            total_len: 20,
+            largest_fragment_capacity: 16,
+        }
+    );
+}
+
+#[test]
+fn growth_stats_linear() {
+    let vec = ImpVec::with_linear_growth(3);
+    vec.imp_extend_from_slice(&[0; 20]);
+    assert_eq!(
+        vec.growth_stats(),
+        GrowthStats {
+            fragment_count: 3,
+            total_capacity: 24,
+            total_len: 20,
+            largest_fragment_capacity: 8,
+        }
+    );
+}
+
+#[test]
+fn growth_stats_recursive() {
+    let vec = ImpVec::with_recursive_growth();
+    vec.imp_extend_from_slice(&['a'; 4]);
+    vec.set_next_fragment_capacity(100);
+    vec.imp_extend_from_slice(&['b'; 101]);
+    assert_eq!(
+        vec.growth_stats(),
+        GrowthStats {
+            fragment_count: 3,
+            total_capacity: 4 + 100 + 200,
+            total_len: 105,
+            largest_fragment_capacity: 200,
+        }
+    );
+}
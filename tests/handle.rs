@@ -0,0 +1,20 @@
+use orx_imp_vec::*;
+
+#[test]
+fn push_handle() {
+    let vec = ImpVec::with_linear_growth(2);
+
+    let handles: Vec<_> = (0..100).map(|i| vec.push_handle(i.to_string())).collect();
+    vec.imp_extend_from_slice(&["x".to_string(), "y".to_string()]);
+
+    assert_eq!(vec.len(), 102);
+    for (i, handle) in handles.iter().enumerate() {
+        assert_eq!(handle.as_str(), i.to_string());
+        assert_eq!(**handle, vec[i]);
+        assert!(std::ptr::eq(handle.get(), &vec[i]));
+    }
+
+    let copy = handles[7];
+    assert_eq!(&*copy, "7");
+    assert_eq!(format!("{:?}", copy), "Handle(\"7\")");
+}
@@ -1,4 +1,5 @@
 use orx_imp_vec::*;
+use std::fmt::Debug;
 
 #[test]
 fn new_default() {
@@ -65,3 +66,597 @@ fn clone() {
     let clone = imp_vec.clone();
     assert_eq!(&[1, 4, 2, 1, 7], &clone.into_inner());
 }
+
+#[test]
+fn into_fixed() {
+    let imp_vec = ImpVec::with_linear_growth(4);
+    for i in 0..100 {
+        imp_vec.imp_push(i.to_string());
+    }
+    assert!(imp_vec.fragments().len() > 1);
+
+    let fixed = imp_vec.into_fixed();
+    assert_eq!(fixed.len(), 100);
+    assert_eq!(fixed.capacity(), fixed.len());
+    for i in 0..100 {
+        assert_eq!(fixed[i], i.to_string());
+    }
+}
+
+#[test]
+fn imp_extend_up_to_capacity() {
+    let imp_vec = ImpVec::with_fixed_capacity(10);
+    imp_vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7]);
+
+    let ref_to_first = &imp_vec[0];
+
+    let num_appended = imp_vec.imp_extend_up_to_capacity(&[8, 9, 10, 11]);
+    assert_eq!(num_appended, 2);
+    assert_eq!(&imp_vec, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9].as_slice());
+
+    let num_appended = imp_vec.imp_extend_up_to_capacity(&[10]);
+    assert_eq!(num_appended, 0);
+    assert_eq!(imp_vec.len(), 10);
+
+    assert_eq!(ref_to_first, &0);
+}
+
+#[test]
+fn imp_extend_up_to_capacity_dynamic() {
+    let imp_vec = ImpVec::new();
+    for i in 0..100 {
+        let slice: Vec<_> = (0..i).collect();
+        assert_eq!(imp_vec.imp_extend_up_to_capacity(&slice), i);
+    }
+    assert_eq!(imp_vec.len(), (0..100).sum::<usize>());
+}
+
+#[test]
+fn imp_extend_count() {
+    fn test<P: PinnedVec<String>>(vec: ImpVec<String, P>) {
+        assert_eq!(vec.imp_extend_count(core::iter::empty()), 0);
+
+        vec.imp_push("first".to_string());
+        let ref_to_first = &vec[0];
+
+        let count = vec.imp_extend_count((0..100).filter(|x| x % 7 == 0).map(|x| x.to_string()));
+        assert_eq!(count, 15);
+        assert_eq!(vec.len(), 16);
+
+        let count = vec.imp_extend_count(vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(count, 2);
+        assert_eq!(vec.len(), 18);
+
+        assert_eq!(ref_to_first, "first");
+        for (i, x) in (0..100).filter(|x| x % 7 == 0).enumerate() {
+            assert_eq!(vec[1 + i], x.to_string());
+        }
+        assert_eq!(&vec[16], "a");
+        assert_eq!(&vec[17], "b");
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(18));
+}
+
+#[test]
+fn into_vec_maybe_zero_copy() {
+    // single fragment
+    let imp_vec = ImpVec::with_linear_growth(4);
+    imp_vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(imp_vec.fragments().len(), 1);
+    let ptr = &imp_vec[0] as *const i32;
+
+    let vec = imp_vec.into_vec_maybe_zero_copy();
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    // fixed
+    let imp_vec = ImpVec::with_fixed_capacity(10);
+    imp_vec.imp_extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7]);
+    let ptr = &imp_vec[0] as *const i32;
+
+    let vec = imp_vec.into_vec_maybe_zero_copy();
+    assert_eq!(vec.as_ptr(), ptr);
+    assert_eq!(vec, [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    // multiple fragments
+    let imp_vec = ImpVec::with_doubling_growth();
+    for i in 0..100 {
+        imp_vec.imp_push(i.to_string());
+    }
+    assert!(imp_vec.fragments().len() > 1);
+
+    let vec = imp_vec.into_vec_maybe_zero_copy();
+    let expected: Vec<_> = (0..100).map(|x| x.to_string()).collect();
+    assert_eq!(vec, expected);
+}
+
+#[test]
+fn imp_extend_from_impvec() {
+    let doubling = ImpVec::with_doubling_growth();
+    for i in 0..50 {
+        doubling.imp_push(i.to_string());
+    }
+    let first = &doubling[0];
+
+    let linear = ImpVec::with_linear_growth(3);
+    for i in 50..120 {
+        linear.imp_push(i.to_string());
+    }
+
+    doubling.imp_extend_from_impvec(&linear);
+
+    let expected: Vec<_> = (0..120).map(|x| x.to_string()).collect();
+    assert_eq!(&doubling, &expected);
+    assert_eq!(linear.len(), 70);
+    assert_eq!(first, "0");
+
+    let fixed = ImpVec::with_fixed_capacity(240);
+    fixed.imp_extend_from_impvec(&doubling);
+    fixed.imp_extend_from_impvec(&fixed);
+    assert_eq!(fixed.len(), 240);
+    for i in 0..240 {
+        assert_eq!(fixed[i], (i % 120).to_string());
+    }
+}
+
+#[test]
+fn imp_push_if() {
+    let vec = ImpVec::with_linear_growth(2);
+    let is_increasing = |value: i32| move |last: Option<&i32>| last.is_none_or(|l| *l < value);
+
+    let values = [3, 1, 5, 5, 4, 8, 10, 9, 11];
+    let pushed: Vec<_> = values
+        .iter()
+        .map(|x| vec.imp_push_if(*x, is_increasing(*x)))
+        .collect();
+
+    assert_eq!(
+        pushed,
+        [true, false, true, false, false, true, true, false, true]
+    );
+    assert_eq!(&vec, [3, 5, 8, 10, 11].as_slice());
+
+    let empty = ImpVec::<i32>::new();
+    assert!(!empty.imp_push_if(0, |last| last.is_some()));
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn clone_into_fixed() {
+    fn test<P: PinnedVec<String> + Debug>(mut vec: ImpVec<String, P>) {
+        for i in 0..100 {
+            vec.imp_push(i.to_string());
+        }
+
+        let fixed = vec.clone_into_fixed();
+        assert_eq!(fixed.capacity(), 100);
+        assert_eq!(&fixed, &vec);
+
+        vec[0] = "changed".to_string();
+        vec.imp_push("new".to_string());
+        _ = vec.pop();
+        _ = vec.pop();
+        vec.clear();
+
+        assert_eq!(fixed.len(), 100);
+        for i in 0..100 {
+            assert_eq!(fixed[i], i.to_string());
+        }
+
+        let empty = vec.clone_into_fixed();
+        assert!(empty.is_empty());
+        assert_eq!(empty.capacity(), 0);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(3));
+    test(ImpVec::with_fixed_capacity(200));
+}
+
+#[test]
+fn on_grow() {
+    use std::sync::{Arc, Mutex};
+
+    let capacities = Arc::new(Mutex::new(vec![]));
+    let log = capacities.clone();
+
+    let mut vec = ImpVec::with_doubling_growth();
+    vec.on_grow(Box::new(move |capacity| log.lock().unwrap().push(capacity)));
+    assert_eq!(vec.capacity(), 4);
+
+    for i in 0..4 {
+        vec.imp_push(i);
+    }
+    assert!(capacities.lock().unwrap().is_empty());
+
+    vec.push(4);
+    for i in 5..29 {
+        vec.imp_push(i);
+    }
+
+    assert_eq!(vec.fragments().len(), 4);
+    assert_eq!(capacities.lock().unwrap().as_slice(), &[12, 28, 60]);
+    assert!(capacities.lock().unwrap().windows(2).all(|w| w[0] < w[1]));
+
+    let clone = vec.clone();
+    for i in 29..100 {
+        clone.imp_push(i);
+    }
+    assert_eq!(capacities.lock().unwrap().len(), 3);
+}
+
+#[test]
+fn on_grow_after_shrinking() {
+    use std::sync::{Arc, Mutex};
+
+    let capacities = Arc::new(Mutex::new(vec![]));
+    let log = capacities.clone();
+
+    let mut vec = ImpVec::with_linear_growth(2);
+    vec.on_grow(Box::new(move |capacity| log.lock().unwrap().push(capacity)));
+    vec.imp_extend_from_slice(&[0; 9]);
+    assert_eq!(capacities.lock().unwrap().as_slice(), &[12]);
+
+    vec.truncate(2);
+    assert_eq!(vec.capacity(), 4);
+
+    vec.imp_extend_from_slice(&[0; 3]);
+    assert_eq!(capacities.lock().unwrap().as_slice(), &[12, 8]);
+}
+
+#[test]
+fn imp_push_with_many_fragments() {
+    let n = 200_000;
+
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..n {
+        vec.imp_push(i);
+    }
+    assert_eq!(vec.fragments().len(), n / 4);
+
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..n {
+        assert_eq!(vec.imp_push_get_ref(i), &i);
+    }
+    assert_eq!(vec.fragments().len(), n / 4);
+
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..n / 2 {
+        vec.imp_extend_from_slice(&[2 * i, 2 * i + 1]);
+    }
+    assert_eq!(vec.fragments().len(), n / 4);
+    assert!((0..n).all(|i| vec[i] == i));
+}
+
+#[test]
+fn imp_vec_is_send() {
+    fn is_send<X: Send>(_: &X) {}
+
+    let mut vec: ImpVec<i32> = ImpVec::new();
+    is_send(&vec);
+
+    vec.on_grow(Box::new(|_| {}));
+    let handle = std::thread::spawn(move || {
+        vec.imp_extend_from_slice(&[0; 10]);
+        vec.len()
+    });
+    assert_eq!(handle.join().unwrap(), 10);
+}
+
+#[test]
+fn seal() {
+    let mut vec = ImpVec::with_linear_growth(2);
+    vec.imp_extend_from_slice(&[0, 1, 2, 3, 4]);
+    assert!(!vec.is_sealed());
+
+    vec.seal();
+    assert!(vec.is_sealed());
+
+    // reads and in-place mutations
+    assert_eq!(vec.len(), 5);
+    assert_eq!(vec.get(2), Some(&2));
+    assert_eq!(vec.sum_by(|x| *x), 10);
+    vec[0] = 42;
+    assert_eq!(&vec, [42, 1, 2, 3, 4].as_slice());
+
+    // clones remain sealed
+    let clone = vec.clone();
+    assert!(clone.is_sealed());
+
+    // consuming gives back a growable pinned vector
+    let mut pinned = vec.into_inner();
+    pinned.push(5);
+    assert_eq!(pinned.len(), 6);
+}
+
+#[test]
+#[should_panic(expected = "cannot push to a sealed imp-vec")]
+fn seal_rejects_imp_push() {
+    let vec = ImpVec::new();
+    vec.imp_push(0);
+    vec.seal();
+    vec.imp_push(1);
+}
+
+#[test]
+#[should_panic(expected = "cannot push to a sealed imp-vec")]
+fn seal_rejects_imp_extend_from_slice() {
+    let vec = ImpVec::with_fixed_capacity(10);
+    vec.seal();
+    vec.imp_extend_from_slice(&[0, 1]);
+}
+
+#[test]
+fn seal_rejects_imp_methods() {
+    fn assert_panics(f: impl FnOnce()) {
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+        assert!(result.is_err());
+    }
+
+    let mut vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[0, 1, 2]);
+    vec.seal();
+
+    let other = ImpVec::new();
+    other.imp_push(3);
+
+    assert_panics(|| vec.imp_push(3));
+    assert_panics(|| _ = vec.imp_push_get_ref(3));
+    assert_panics(|| _ = vec.imp_push_get_ref_and_index(3));
+    assert_panics(|| vec.imp_push_array([3, 4]));
+    assert_panics(|| _ = vec.imp_push_array_get_refs([3, 4]));
+    assert_panics(|| _ = vec.imp_extend_array_get_refs([3, 4]));
+    assert_panics(|| _ = vec.imp_push_if(3, |_| true));
+    assert_panics(|| vec.imp_extend_from_slice(&[3]));
+    assert_panics(|| vec.imp_pad_to(4, 3));
+    assert_panics(|| _ = vec.imp_extend_up_to_capacity(&[3]));
+    assert_panics(|| _ = vec.imp_extend_count([3]));
+    assert_panics(|| vec.imp_extend_from_impvec(&other));
+    assert_panics(|| vec.push(3));
+
+    assert!(vec.try_imp_push(3).is_err());
+    assert_eq!(vec.try_push_get_ref(3), Err(3));
+
+    assert_eq!(&vec, [0, 1, 2].as_slice());
+}
+
+#[test]
+fn seal_does_not_guard_deref_mut() {
+    let mut vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[0, 1, 2]);
+    vec.seal();
+
+    PinnedVec::push(&mut *vec, 3);
+    vec.extend_from_slice(&[4]);
+    assert!(vec.is_sealed());
+    assert_eq!(&vec, [0, 1, 2, 3, 4].as_slice());
+}
+
+#[test]
+fn leak_refs() {
+    fn test<P: PinnedVec<String> + 'static>(vec: ImpVec<String, P>) {
+        for i in 0..100 {
+            vec.imp_push(i.to_string());
+        }
+        let ptrs: Vec<_> = (0..100).map(|i| vec.get_ptr(i).unwrap()).collect();
+
+        let refs = vec.leak_refs();
+
+        assert_eq!(refs.len(), 100);
+        for (i, x) in refs.iter().enumerate() {
+            assert_eq!(*x as *const String, ptrs[i]);
+            assert_eq!(*x, &i.to_string());
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(3));
+    test(ImpVec::with_fixed_capacity(100));
+
+    let refs = {
+        let vec = ImpVec::new();
+        vec.imp_extend_from_slice(&[0, 1, 2]);
+        vec.leak_refs()
+    };
+    assert_eq!(refs, [&0, &1, &2]);
+}
+
+#[test]
+fn imp_push_get_ref_and_index() {
+    fn test<P: PinnedVec<String>>(vec: ImpVec<String, P>) {
+        let mut refs = vec![];
+        for i in 0..100 {
+            let (idx, x) = vec.imp_push_get_ref_and_index(i.to_string());
+            assert_eq!(idx, i);
+            assert_eq!(x, &i.to_string());
+            assert!(core::ptr::eq(x, &vec[idx]));
+            refs.push((idx, x));
+        }
+
+        for (idx, x) in refs {
+            assert!(core::ptr::eq(x, &vec[idx]));
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+fn imp_pad_to() {
+    use std::{cell::Cell, rc::Rc};
+
+    struct Counted(Rc<Cell<usize>>);
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            Self(self.0.clone())
+        }
+    }
+
+    fn test<P: PinnedVec<Counted>>(vec: ImpVec<Counted, P>) {
+        let num_clones = Rc::new(Cell::new(0));
+        let sentinel = || Counted(num_clones.clone());
+
+        vec.imp_pad_to(0, sentinel());
+        assert_eq!(vec.len(), 0);
+
+        vec.imp_push(sentinel());
+        vec.imp_push(sentinel());
+        assert_eq!(num_clones.get(), 0);
+
+        vec.imp_pad_to(10, sentinel());
+        assert_eq!(vec.len(), 10);
+        assert_eq!(num_clones.get(), 7);
+
+        vec.imp_pad_to(5, sentinel());
+        vec.imp_pad_to(10, sentinel());
+        assert_eq!(vec.len(), 10);
+        assert_eq!(num_clones.get(), 7);
+
+        vec.imp_pad_to(11, sentinel());
+        assert_eq!(vec.len(), 11);
+        assert_eq!(num_clones.get(), 7);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(11));
+}
+
+#[test]
+fn into_partition() {
+    fn test<P: PinnedVec<String>>(vec: ImpVec<String, P>) {
+        for i in 0..100 {
+            vec.imp_push(i.to_string());
+        }
+
+        let (evens, odds) = vec.into_partition(|x| x.parse::<usize>().expect("number") % 2 == 0);
+
+        assert_eq!(evens.len(), 50);
+        assert_eq!(odds.len(), 50);
+        for (i, (even, odd)) in evens.iter().zip(&odds).enumerate() {
+            assert_eq!(even, &(2 * i).to_string());
+            assert_eq!(odd, &(2 * i + 1).to_string());
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(100));
+
+    let vec: ImpVec<u32> = ImpVec::new();
+    let (yes, no) = vec.into_partition(|_| true);
+    assert!(yes.is_empty() && no.is_empty());
+}
+
+#[test]
+fn imp_push_array() {
+    fn test<P: PinnedVec<i32>>(vec: ImpVec<i32, P>) {
+        vec.imp_push_array([]);
+        assert!(vec.is_empty());
+
+        vec.imp_push_array([0, 1, 2, 3]);
+        let first = &vec[0];
+
+        let mut refs: Vec<&i32> = vec![];
+        for i in 1..25 {
+            let batch = vec.imp_push_array_get_refs([4 * i, 4 * i + 1, 4 * i + 2, 4 * i + 3]);
+            for (j, x) in batch.iter().enumerate() {
+                let idx = 4 * i as usize + j;
+                assert!(core::ptr::eq(*x, &vec[idx]));
+            }
+            refs.extend(batch);
+        }
+
+        assert_eq!(first, &0);
+        assert_eq!(vec.len(), 100);
+        for i in 0..100 {
+            assert_eq!(vec[i], i as i32);
+        }
+        for (i, x) in refs.iter().enumerate() {
+            assert_eq!(**x, i as i32 + 4);
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(3));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+fn imp_extend_array_get_refs() {
+    fn test<P: PinnedVec<i32>>(vec: ImpVec<i32, P>) {
+        let [a, b, c] = vec.imp_extend_array_get_refs([1, 2, 3]);
+
+        for i in 4..100 {
+            vec.imp_push(i);
+        }
+        let [d, e, f] = vec.imp_extend_array_get_refs([100, 101, 102]);
+
+        assert_eq!(a + b + c, 6);
+        assert_eq!([a, b, c, d, e, f], [&1, &2, &3, &100, &101, &102]);
+        assert!(core::ptr::eq(a, &vec[0]));
+        assert!(core::ptr::eq(c, &vec[2]));
+        assert!(core::ptr::eq(f, &vec[101]));
+        assert_eq!(vec.len(), 102);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(102));
+}
+
+#[test]
+fn try_push_get_ref() {
+    let vec = ImpVec::with_fixed_capacity(3);
+
+    let a = vec.try_push_get_ref("a".to_string()).unwrap();
+    let b = vec.try_push_get_ref("b".to_string()).unwrap();
+    let c = vec.try_push_get_ref("c".to_string()).unwrap();
+    assert_eq!(vec.try_push_get_ref("d".to_string()), Err("d".to_string()));
+    assert_eq!(vec.len(), 3);
+    assert_eq!([a, b, c], ["a", "b", "c"]);
+
+    let vec = ImpVec::with_linear_growth(2);
+    let refs: Vec<_> = (0..100).map(|i| vec.try_push_get_ref(i).unwrap()).collect();
+    for (i, x) in refs.iter().enumerate() {
+        assert_eq!(*x, &i);
+    }
+
+    vec.seal();
+    assert_eq!(vec.try_push_get_ref(100), Err(100));
+    assert_eq!(vec.len(), 100);
+}
+
+#[test]
+fn try_push_with_many_fragments() {
+    let n = 200_000;
+
+    let vec = ImpVec::with_linear_growth(2);
+    for i in 0..n {
+        assert_eq!(vec.try_push_get_ref(i), Ok(&i));
+    }
+    for i in 0..n {
+        assert!(vec.try_imp_push(i).is_ok());
+    }
+    assert_eq!(vec.fragments().len(), n / 2);
+
+    let vec = ImpVec::with_fixed_capacity(n);
+    for i in 0..n {
+        assert!(vec.try_imp_push(i).is_ok());
+    }
+    assert!(vec.try_imp_push(n).is_err());
+}
@@ -25,3 +25,31 @@ fn index_mut() {
 
     assert_eq!(&vec, [0, 2, 4, 6, 8].as_slice());
 }
+
+#[test]
+fn index_node_id() {
+    let mut vec = ImpVec::with_linear_growth(2);
+    for i in 0..20 {
+        vec.imp_push(i.to_string());
+    }
+
+    for i in 0..vec.len() {
+        let id = NodeId(i);
+        assert_eq!(&vec[id], &vec[i]);
+        assert_eq!(vec.get_node(id), vec.get(i));
+        assert_eq!(NodeId::from(i), id);
+        assert_eq!(usize::from(id), i);
+    }
+    assert_eq!(vec.get_node(NodeId(20)), None);
+
+    vec[NodeId(3)] = "x".to_string();
+    assert_eq!(vec[3], "x");
+}
+
+#[test]
+#[should_panic]
+fn index_node_id_out_of_bounds() {
+    let vec = ImpVec::new();
+    vec.imp_push(0);
+    let _ = vec[NodeId(1)];
+}
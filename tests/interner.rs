@@ -0,0 +1,38 @@
+#![cfg(feature = "std")]
+
+use orx_imp_vec::*;
+
+#[test]
+fn interner() {
+    let interner = ImpVec::interner();
+    assert!(interner.is_empty());
+
+    let num_distinct = 50;
+    let mut indices = vec![];
+    for i in 0..1000 {
+        let value = format!("s{}", (i * 7) % num_distinct);
+        indices.push(interner.intern(value));
+    }
+
+    // dedup
+    assert_eq!(interner.len(), num_distinct);
+    let first_ref = interner.resolve(0);
+
+    // resolution
+    for (i, index) in indices.iter().enumerate() {
+        let expected = format!("s{}", (i * 7) % num_distinct);
+        assert_eq!(interner.resolve(*index), &expected);
+        assert_eq!(interner.intern(expected), *index);
+    }
+
+    // stable indices and references
+    for i in 0..num_distinct {
+        let value = format!("s{}", i);
+        let index = interner.intern(value.clone());
+        assert_eq!(interner.resolve(index), &value);
+    }
+    let new_index = interner.intern(String::from("new"));
+    assert_eq!(new_index, num_distinct);
+    assert_eq!(first_ref, "s0");
+    assert_eq!(interner.values().len(), num_distinct + 1);
+}
@@ -0,0 +1,60 @@
+use orx_imp_vec::*;
+
+fn consume_in_parallel<I: IntoConcurrentIter<Item = String>>(vec: I) -> Vec<String> {
+    let iter = vec.into_con_iter();
+    let mut collected: Vec<String> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut owned = vec![];
+                    while let Some(x) = iter.next() {
+                        owned.push(x);
+                    }
+                    owned
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().expect("thread must not panic"))
+            .collect()
+    });
+    collected.sort();
+    collected
+}
+
+#[test]
+fn into_con_iter_split_vec() {
+    let vec = ImpVec::new();
+    for i in 0..1000 {
+        vec.imp_push(i.to_string());
+    }
+    let mut expected: Vec<_> = (0..1000).map(|i| i.to_string()).collect();
+    expected.sort();
+
+    assert_eq!(consume_in_parallel(vec), expected);
+
+    let vec = ImpVec::with_linear_growth(4);
+    for i in 0..1000 {
+        vec.imp_push(i.to_string());
+    }
+    assert_eq!(consume_in_parallel(vec), expected);
+}
+
+#[test]
+fn into_con_iter_fixed_vec() {
+    let vec = ImpVec::with_fixed_capacity(1000);
+    for i in 0..1000 {
+        vec.imp_push(i.to_string());
+    }
+    let mut expected: Vec<_> = (0..1000).map(|i| i.to_string()).collect();
+    expected.sort();
+
+    assert_eq!(consume_in_parallel(vec), expected);
+}
+
+#[test]
+fn into_con_iter_empty() {
+    let vec: ImpVec<String> = ImpVec::new();
+    assert!(consume_in_parallel(vec).is_empty());
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "std")]
+
+use orx_imp_vec::*;
+
+#[derive(Debug, PartialEq)]
+struct City {
+    name: String,
+    population: usize,
+}
+
+fn city(name: &str, population: usize) -> City {
+    City {
+        name: name.to_string(),
+        population,
+    }
+}
+
+#[test]
+fn into_map() {
+    fn test<P: PinnedVec<City>>(vec: ImpVec<City, P>) {
+        vec.imp_push(city("amsterdam", 1));
+        vec.imp_push(city("berlin", 2));
+        vec.imp_push(city("copenhagen", 3));
+        vec.imp_push(city("berlin", 4));
+
+        let map = vec.into_map(|x| x.name.clone());
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get("amsterdam"), Some(&city("amsterdam", 1)));
+        assert_eq!(map.get("berlin"), Some(&city("berlin", 4)));
+        assert_eq!(map.get("copenhagen").map(|x| x.population), Some(3));
+        assert_eq!(map.get("dublin"), None);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(1));
+    test(ImpVec::with_fixed_capacity(4));
+}
+
+#[test]
+fn into_map_empty() {
+    let vec: ImpVec<City> = ImpVec::new();
+    let map = vec.into_map(|x| x.population);
+    assert!(map.is_empty());
+}
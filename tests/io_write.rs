@@ -0,0 +1,59 @@
+#![cfg(feature = "std")]
+
+use orx_imp_vec::*;
+use std::io::{ErrorKind, Write};
+
+#[test]
+fn io_write() {
+    fn test<P: PinnedVec<u8>>(vec: ImpVec<u8, P>) {
+        let mut expected = String::new();
+        for i in 0..100 {
+            write!(&vec, "{},", i).unwrap();
+            expected.push_str(&format!("{},", i));
+        }
+        (&vec).flush().unwrap();
+
+        assert_eq!(vec.len(), expected.len());
+        for (i, b) in expected.bytes().enumerate() {
+            assert_eq!(vec[i], b);
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(3));
+    test(ImpVec::with_fixed_capacity(1000));
+}
+
+#[test]
+fn io_write_holding_references() {
+    let vec = ImpVec::new();
+    (&vec).write_all(b"abc").unwrap();
+    let first = &vec[0];
+
+    for _ in 0..100 {
+        (&vec).write_all(b"def").unwrap();
+    }
+
+    assert_eq!(vec.len(), 303);
+    assert_eq!(first, &b'a');
+}
+
+#[test]
+fn io_write_fixed_capacity() {
+    let vec = ImpVec::with_fixed_capacity(4);
+    assert_eq!((&vec).write(b"abcdef").unwrap(), 4);
+    assert_eq!(&vec, b"abcd".as_slice());
+
+    let error = (&vec).write_all(b"e").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::WriteZero);
+}
+
+#[test]
+fn io_write_sealed() {
+    let vec = ImpVec::new();
+    (&vec).write_all(b"abc").unwrap();
+    vec.seal();
+    assert!((&vec).write_all(b"def").is_err());
+    assert_eq!(&vec, b"abc".as_slice());
+}
@@ -0,0 +1,214 @@
+use orx_imp_vec::*;
+
+#[test]
+fn try_for_each() {
+    let vec = ImpVec::with_linear_growth(4);
+    for i in 0..1000 {
+        vec.imp_push(i);
+    }
+    assert!(vec.fragments().len() > 50);
+
+    let mut num_visited = 0;
+    let result = vec.try_for_each(|x| {
+        num_visited += 1;
+        match *x < 10000 {
+            true => Ok(()),
+            false => Err(*x),
+        }
+    });
+    assert_eq!(result, Ok(()));
+    assert_eq!(num_visited, 1000);
+
+    let mut num_visited = 0;
+    let result = vec.try_for_each(|x| {
+        num_visited += 1;
+        match *x != 871 {
+            true => Ok(()),
+            false => Err(format!("invalid element {}", x)),
+        }
+    });
+    assert_eq!(result, Err("invalid element 871".to_string()));
+    assert_eq!(num_visited, 872);
+}
+
+#[test]
+fn sum_by() {
+    struct Point {
+        x: i64,
+        y: f32,
+    }
+
+    let vec = ImpVec::new();
+    assert_eq!(vec.sum_by(|p: &Point| p.x), 0);
+
+    let n = 100_000;
+    for i in 0..n {
+        vec.imp_push(Point { x: i - 10, y: 0.5 });
+    }
+
+    let sum_x: i64 = vec.sum_by(|p| p.x);
+    assert_eq!(sum_x, (0..n).map(|i| i - 10).sum::<i64>());
+
+    let sum_y: f32 = vec.sum_by(|p| p.y);
+    assert_eq!(sum_y as i64, n / 2);
+}
+
+#[test]
+fn group_consecutive_by() {
+    let vec = ImpVec::with_linear_growth(2);
+    assert!(vec.group_consecutive_by(|x: &char| *x).is_empty());
+
+    vec.imp_extend_from_slice(&['a', 'a', 'b', 'a', 'a', 'a']);
+    let runs = vec.group_consecutive_by(|x| *x);
+    let sizes: Vec<_> = runs.iter().map(|x| x.len()).collect();
+    assert_eq!(sizes, [2, 1, 3]);
+    assert_eq!(runs[0], [&'a', &'a']);
+    assert_eq!(runs[1], [&'b']);
+    assert_eq!(runs[2], [&'a', &'a', &'a']);
+    assert!(std::ptr::eq(runs[2][0], &vec[3]));
+
+    let vec = ImpVec::with_linear_growth(3);
+    for i in 0..100 {
+        vec.imp_push(i);
+    }
+    let runs = vec.group_consecutive_by(|x| x / 10);
+    assert_eq!(runs.len(), 10);
+    for (i, run) in runs.iter().enumerate() {
+        let expected: Vec<_> = (i * 10..(i + 1) * 10).collect();
+        assert_eq!(run.iter().map(|x| **x).collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn windows() {
+    fn test<P: PinnedVec<usize>>(vec: ImpVec<usize, P>) {
+        assert_eq!(vec.windows(3).count(), 0);
+
+        for i in 0..100 {
+            vec.imp_push(i);
+        }
+
+        let windows: Vec<_> = vec.windows(3).collect();
+        assert_eq!(windows.len(), 98);
+        for (i, window) in windows.iter().enumerate() {
+            assert_eq!(window, &[&i, &(i + 1), &(i + 2)]);
+            for (j, x) in window.iter().enumerate() {
+                assert!(core::ptr::eq(*x, &vec[i + j]));
+            }
+        }
+
+        assert_eq!(vec.windows(1).count(), 100);
+        assert_eq!(vec.windows(100).count(), 1);
+        assert_eq!(vec.windows(101).count(), 0);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn windows_zero_size() {
+    let vec = ImpVec::new();
+    vec.imp_push(0);
+    let _ = vec.windows(0);
+}
+
+#[test]
+fn scan_state() {
+    fn test<P: PinnedVec<u64>>(vec: ImpVec<u64, P>) {
+        assert_eq!(vec.scan_state(0, |s, x| *s + x).count(), 0);
+
+        for i in 1..=100 {
+            vec.imp_push(i);
+        }
+
+        let running_sums: Vec<_> = vec
+            .scan_state(0, |sum, x| {
+                *sum += x;
+                *sum
+            })
+            .collect();
+        assert_eq!(running_sums.len(), 100);
+        for (i, sum) in running_sums.iter().enumerate() {
+            let n = i as u64 + 1;
+            assert_eq!(*sum, n * (n + 1) / 2);
+        }
+
+        // state differing from the yielded type
+        let counts_match: Vec<_> = vec
+            .scan_state(Vec::new(), |seen, x| {
+                seen.push(*x);
+                seen.len() as u64 == *x
+            })
+            .collect();
+        assert!(counts_match.iter().all(|x| *x));
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+fn iter_peekable() {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Token {
+        Num(i64),
+        Plus,
+        Minus,
+    }
+
+    // expr := num (('+' | '-') num)*
+    fn evaluate<P: PinnedVec<Token>>(tokens: &ImpVec<Token, P>) -> Option<i64> {
+        fn num<'a, I: Iterator<Item = &'a Token>>(
+            iter: &mut core::iter::Peekable<I>,
+        ) -> Option<i64> {
+            match iter.next()? {
+                Token::Num(x) => Some(*x),
+                _ => None,
+            }
+        }
+
+        let mut iter = tokens.iter_peekable();
+        let mut result = num(&mut iter)?;
+        while let Some(op) = iter.next_if(|x| matches!(x, Token::Plus | Token::Minus)) {
+            let rhs = num(&mut iter)?;
+            match op {
+                Token::Plus => result += rhs,
+                _ => result -= rhs,
+            }
+        }
+        iter.peek().is_none().then_some(result)
+    }
+
+    fn test<P: PinnedVec<Token>>(vec: ImpVec<Token, P>) {
+        assert_eq!(evaluate(&vec), None);
+
+        vec.imp_push(Token::Num(0));
+        for i in 1..=100 {
+            vec.imp_push(Token::Plus);
+            vec.imp_push(Token::Num(i));
+        }
+        vec.imp_push(Token::Minus);
+        vec.imp_push(Token::Num(50));
+        assert_eq!(evaluate(&vec), Some(5050 - 50));
+
+        vec.imp_push(Token::Num(1));
+        assert_eq!(evaluate(&vec), None);
+
+        let mut iter = vec.iter_peekable();
+        assert_eq!(iter.peek(), Some(&&Token::Num(0)));
+        assert_eq!(iter.next(), Some(&Token::Num(0)));
+        assert_eq!(iter.peek(), Some(&&Token::Plus));
+        assert_eq!(iter.count(), vec.len() - 1);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(204));
+}
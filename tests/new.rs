@@ -44,3 +44,141 @@ fn new() {
     }
     test(imp);
 }
+
+#[test]
+fn from_exact_size_iter() {
+    let imp = ImpVec::from_exact_size_iter(0..1000);
+    assert_eq!(imp.len(), 1000);
+    assert_eq!(imp.fragments().len(), 1);
+    assert_eq!(imp.capacity(), 1000);
+    for i in 0..1000 {
+        assert_eq!(imp[i], i);
+    }
+
+    let imp = ImpVec::from_exact_size_iter((13..542).map(|x| x.to_string()));
+    assert_eq!(imp.fragments().len(), 1);
+    for i in 13..542 {
+        assert_eq!(imp.get(i - 13), Some(&i.to_string()));
+    }
+
+    imp.imp_push(542.to_string());
+    assert_eq!(imp.fragments().len(), 2);
+
+    let imp = ImpVec::<char, _>::from_exact_size_iter(None);
+    assert!(imp.is_empty());
+    imp.imp_extend_from_slice(&['a', 'b', 'c', 'd', 'e']);
+    assert_eq!(&imp, ['a', 'b', 'c', 'd', 'e'].as_slice());
+}
+
+#[test]
+#[should_panic(
+    expected = "constant_fragment_capacity_exponent 100 is too large: the fragment capacity 2 ^ 100 overflows usize::MAX"
+)]
+fn with_linear_growth_overflowing_exponent() {
+    let _: ImpVec<char, _> = ImpVec::with_linear_growth(100);
+}
+
+#[test]
+fn collect_array() {
+    // exact
+    let imp = ImpVec::collect_array::<100, _>((0..100).map(|x| x.to_string())).unwrap();
+    assert_eq!(imp.len(), 100);
+    assert_eq!(imp.capacity(), 100);
+    for i in 0..100 {
+        assert_eq!(imp[i], i.to_string());
+    }
+
+    // under
+    let imp = ImpVec::collect_array::<100, _>((0..42).map(|x| x.to_string())).unwrap();
+    assert_eq!(imp.len(), 42);
+    assert_eq!(imp.capacity(), 100);
+    for i in 0..42 {
+        assert_eq!(imp[i], i.to_string());
+    }
+    for i in 42..100 {
+        imp.imp_push(i.to_string());
+    }
+    assert_eq!(imp.len(), 100);
+
+    let imp = ImpVec::<String, _>::collect_array::<3, _>([]).unwrap();
+    assert!(imp.is_empty());
+
+    // over
+    let mut num_yielded = 0;
+    let iter = (0..1000).inspect(|_| num_yielded += 1);
+    assert!(ImpVec::collect_array::<100, _>(iter).is_none());
+    assert_eq!(num_yielded, 101);
+
+    assert!(ImpVec::collect_array::<0, _>([42]).is_none());
+}
+
+#[test]
+fn try_from_iter() {
+    let vec: Result<ImpVec<usize>, String> = ImpVec::try_from_iter((0..1000).map(Ok));
+    let vec = vec.expect("no errors");
+    assert_eq!(vec.len(), 1000);
+    for i in 0..1000 {
+        assert_eq!(vec[i], i);
+    }
+
+    let empty: Result<ImpVec<usize>, String> = ImpVec::try_from_iter([]);
+    assert_eq!(empty.map(|x| x.len()), Ok(0));
+
+    let mut num_visited = 0;
+    let iter = (0..1000).map(|i| {
+        num_visited += 1;
+        match i {
+            42 | 100 => Err(format!("failed at {}", i)),
+            _ => Ok(i),
+        }
+    });
+    let result = ImpVec::try_from_iter(iter);
+    assert_eq!(result.err(), Some("failed at 42".to_string()));
+    assert_eq!(num_visited, 43);
+}
+
+#[test]
+fn with_exact_capacity() {
+    for n in [0, 1, 4, 100] {
+        let vec = ImpVec::with_exact_capacity(n);
+        assert_eq!(vec.capacity(), n);
+
+        for i in 0..n {
+            assert!(!vec.is_full());
+            vec.imp_push(i);
+        }
+        assert!(vec.is_full());
+        assert_eq!(vec.len(), n);
+        assert_eq!(
+            vec.try_imp_push(n),
+            Err(ImpVecError::CapacityExceeded {
+                capacity: n,
+                needed: n + 1
+            })
+        );
+    }
+}
+
+#[test]
+fn with_defaults() {
+    let vec: ImpVec<String, _> = ImpVec::with_defaults(0);
+    assert!(vec.is_empty());
+
+    let mut vec: ImpVec<String, _> = ImpVec::with_defaults(100);
+    assert_eq!(vec.len(), 100);
+
+    vec[50] = "fifty".to_string();
+    vec[99].push('!');
+
+    for i in 0..100 {
+        let expected = match i {
+            50 => "fifty",
+            99 => "!",
+            _ => "",
+        };
+        assert_eq!(vec[i], expected);
+    }
+
+    vec.imp_push("x".to_string());
+    assert_eq!(vec.len(), 101);
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "std")]
+
+use orx_imp_vec::*;
+
+#[test]
+fn par_position() {
+    fn test<P: PinnedVec<usize>>(vec: ImpVec<usize, P>) {
+        let n = 100_000;
+        assert_eq!(vec.par_position(|_| true), None);
+
+        for i in 0..n {
+            vec.imp_push(i);
+        }
+
+        // sole match in a late fragment
+        let target = n - 42;
+        assert_eq!(vec.par_position(|x| *x == target), Some(target));
+
+        // lowest of multiple matches
+        assert_eq!(vec.par_position(|x| *x >= 5_000 && x % 7 == 0), Some(5_005));
+        assert_eq!(vec.par_position(|x| x % 3 == 2), Some(2));
+        assert_eq!(vec.par_position(|_| true), Some(0));
+
+        assert_eq!(vec.par_position(|x| *x >= n), None);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(10));
+    test(ImpVec::with_fixed_capacity(100_000));
+}
+
+#[test]
+fn par_extend() {
+    fn test<P: PinnedVec<String>>(vec: ImpVec<String, P>) {
+        vec.par_extend(Vec::<String>::new());
+        assert!(vec.is_empty());
+
+        vec.imp_push(String::from("x"));
+        let first = &vec[0];
+
+        let source: Vec<_> = (0..10_000).map(|i| i.to_string()).collect();
+        vec.par_extend(source.clone());
+        assert_eq!(vec.len(), 10_001);
+        assert_eq!(first, "x");
+
+        let mut appended: Vec<_> = vec.iter().skip(1).cloned().collect();
+        appended.sort();
+        let mut expected = source;
+        expected.sort();
+        assert_eq!(appended, expected);
+
+        let source: Vec<_> = (0..1000).map(|i| (i % 10).to_string()).collect();
+        vec.par_extend(source.clone());
+        let mut appended: Vec<_> = vec.iter().skip(10_001).cloned().collect();
+        appended.sort();
+        let mut expected = source;
+        expected.sort();
+        assert_eq!(appended, expected);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(4));
+    test(ImpVec::with_fixed_capacity(11_001));
+}
+
+#[test]
+fn par_extend_from_fn() {
+    fn test<P: PinnedVec<String>>(vec: ImpVec<String, P>) {
+        vec.par_extend_from_fn(0, |i| i.to_string());
+        assert!(vec.is_empty());
+
+        vec.imp_push(String::from("x"));
+        vec.par_extend_from_fn(10_000, |i| i.to_string());
+        vec.par_extend_from_fn(3, |i| (i * 10).to_string());
+
+        assert_eq!(vec.len(), 10_004);
+        assert_eq!(&vec[0], "x");
+        for i in 0..10_000 {
+            assert_eq!(vec[1 + i], i.to_string());
+        }
+        assert_eq!(&vec[10_001], "0");
+        assert_eq!(&vec[10_002], "10");
+        assert_eq!(&vec[10_003], "20");
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(10));
+    test(ImpVec::with_fixed_capacity(10_004));
+}
+
+#[test]
+fn par_reduce() {
+    fn test<P: PinnedVec<u64>>(vec: ImpVec<u64, P>) {
+        let sum = |a: &u64, b: &u64| a + b;
+        assert_eq!(vec.par_reduce(0, sum), 0);
+
+        for i in 0..100_000 {
+            vec.imp_push(i * 3 % 1_000);
+        }
+
+        let sequential: u64 = vec.iter().sum();
+        assert_eq!(vec.par_reduce(0, sum), sequential);
+        assert_eq!(vec.par_reduce(u64::MAX, |a, b| *a.min(b)), 0);
+        assert_eq!(vec.par_reduce(0, |a, b| *a.max(b)), 999);
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(10));
+    test(ImpVec::with_fixed_capacity(100_000));
+}
+
+#[test]
+fn par_reduce_non_commutative() {
+    let vec = ImpVec::new();
+    for i in 0..5_000 {
+        vec.imp_push(vec![i]);
+    }
+
+    let concatenated = vec.par_reduce(vec![], |a, b| a.iter().chain(b).copied().collect());
+    assert_eq!(concatenated, (0..5_000).collect::<Vec<usize>>());
+}
@@ -0,0 +1,269 @@
+use orx_imp_vec::*;
+
+struct Node {
+    value: usize,
+    next: Option<*const Node>,
+}
+
+fn values(vec: &ImpVec<Node>) -> Vec<usize> {
+    let mut values = vec![];
+    let mut current = vec.first().map(|x| x as *const Node);
+    while let Some(node) = current {
+        let node = unsafe { &*node };
+        values.push(node.value);
+        current = node.next;
+    }
+    values
+}
+
+#[test]
+fn pop_with() {
+    let mut vec: ImpVec<Node> = ImpVec::new();
+    assert!(vec.pop_with(|_, _| panic!("must not be called")).is_none());
+
+    for i in 0..10 {
+        vec.imp_push(Node {
+            value: i,
+            next: None,
+        });
+    }
+    for i in 0..9 {
+        let next = &vec[i + 1] as *const Node;
+        vec[i].next = Some(next);
+    }
+    assert_eq!(values(&vec), (0..10).collect::<Vec<_>>());
+
+    for i in (1..10).rev() {
+        let popped = vec.pop_with(|vec, last| {
+            assert_eq!(last, i);
+            vec[last - 1].next = None;
+        });
+        assert_eq!(popped.map(|x| x.value), Some(i));
+        assert_eq!(values(&vec), (0..i).collect::<Vec<_>>());
+    }
+
+    let popped = vec.pop_with(|_, last| assert_eq!(last, 0));
+    assert_eq!(popped.map(|x| x.value), Some(0));
+    assert!(vec.is_empty());
+}
+
+#[test]
+fn imp_truncate() {
+    struct Node<'a> {
+        value: String,
+        next: std::cell::Cell<Option<&'a Node<'a>>>,
+    }
+
+    let vec: ImpVec<Node, _> = ImpVec::with_linear_growth(2);
+    let mut prev: Option<&Node> = None;
+    for i in 0..10 {
+        let node = vec.imp_push_get_ref(Node {
+            value: i.to_string(),
+            next: Default::default(),
+        });
+        if let Some(prev) = prev {
+            prev.next.set(Some(node));
+        }
+        prev = Some(node);
+    }
+
+    let first = &vec[0];
+    let fifth = &vec[4];
+
+    // detach references to the tail
+    fifth.next.set(None);
+    unsafe { vec.imp_truncate(5) };
+
+    assert_eq!(vec.len(), 5);
+    let mut values = vec![];
+    let mut current = Some(first);
+    while let Some(node) = current {
+        values.push(node.value.clone());
+        current = node.next.get();
+    }
+    assert_eq!(values, ["0", "1", "2", "3", "4"]);
+
+    unsafe { vec.imp_truncate(10) };
+    assert_eq!(vec.len(), 5);
+
+    vec.imp_push(Node {
+        value: 42.to_string(),
+        next: Default::default(),
+    });
+    assert_eq!(vec[5].value, "42");
+    assert_eq!(first.value, "0");
+}
+
+#[test]
+fn for_each_mut() {
+    let mut vec: ImpVec<Node> = ImpVec::new();
+    vec.for_each_mut(|_| panic!("must not be called"));
+
+    for i in 0..100 {
+        vec.imp_push(Node {
+            value: i,
+            next: None,
+        });
+    }
+    for i in 0..99 {
+        let next = &vec[i + 1] as *const Node;
+        vec[i].next = Some(next);
+    }
+    let ptrs: Vec<_> = (0..100).map(|i| vec.get_ptr(i).unwrap()).collect();
+
+    vec.for_each_mut(|node| node.value *= 2);
+
+    assert_eq!(values(&vec), (0..100).map(|x| x * 2).collect::<Vec<_>>());
+    for (i, ptr) in ptrs.iter().enumerate() {
+        assert_eq!(vec.get_ptr(i), Some(*ptr));
+    }
+}
+
+#[test]
+fn detect_next_cycle() {
+    fn next(node: &Node) -> Option<&Node> {
+        node.next.map(|x| unsafe { &*x })
+    }
+
+    let mut vec: ImpVec<Node> = ImpVec::new();
+    assert_eq!(vec.detect_next_cycle(0, next), None);
+
+    for i in 0..5 {
+        vec.imp_push(Node {
+            value: i,
+            next: None,
+        });
+    }
+    for i in 0..4 {
+        let next = &vec[i + 1] as *const Node;
+        vec[i].next = Some(next);
+    }
+
+    // 0 -> 1 -> 2 -> 3 -> 4
+    for start in 0..5 {
+        assert_eq!(vec.detect_next_cycle(start, next), None);
+    }
+    assert_eq!(vec.detect_next_cycle(5, next), None);
+
+    // 0 -> 1 -> [2 -> 3 -> 4 -> 2]
+    let node2 = &vec[2] as *const Node;
+    vec[4].next = Some(node2);
+    assert_eq!(vec.detect_next_cycle(0, next), Some(2));
+    assert_eq!(vec.detect_next_cycle(1, next), Some(2));
+    assert_eq!(vec.detect_next_cycle(2, next), Some(2));
+    assert_eq!(vec.detect_next_cycle(3, next), Some(3));
+    assert_eq!(vec.detect_next_cycle(4, next), Some(4));
+
+    // 0 -> 1 -> [1]
+    let node1 = &vec[1] as *const Node;
+    vec[1].next = Some(node1);
+    assert_eq!(vec.detect_next_cycle(0, next), Some(1));
+    assert_eq!(vec.detect_next_cycle(1, next), Some(1));
+}
+
+#[test]
+fn cycle_len() {
+    fn next(node: &Node) -> Option<&Node> {
+        node.next.map(|x| unsafe { &*x })
+    }
+
+    let mut vec: ImpVec<Node> = ImpVec::new();
+    assert_eq!(vec.cycle_len(0, next), None);
+
+    for i in 0..8 {
+        vec.imp_push(Node {
+            value: i,
+            next: None,
+        });
+    }
+    for i in 0..7 {
+        let next = &vec[i + 1] as *const Node;
+        vec[i].next = Some(next);
+    }
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7
+    for start in 0..8 {
+        assert_eq!(vec.cycle_len(start, next), None);
+    }
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 0
+    let node0 = &vec[0] as *const Node;
+    vec[6].next = Some(node0);
+    for start in 0..7 {
+        assert_eq!(vec.cycle_len(start, next), Some(7));
+    }
+    assert_eq!(vec.cycle_len(7, next), None);
+    assert_eq!(vec.cycle_len(8, next), None);
+
+    // 7 -> [0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 0]
+    vec[7].next = Some(node0);
+    assert_eq!(vec.cycle_len(7, next), None);
+
+    // 7 -> [7]
+    let node7 = &vec[7] as *const Node;
+    vec[7].next = Some(node7);
+    assert_eq!(vec.cycle_len(7, next), Some(1));
+}
+
+#[test]
+fn unlink() {
+    struct Node {
+        value: usize,
+        prev: Option<*const Node>,
+        next: Option<*const Node>,
+    }
+
+    fn forward(vec: &ImpVec<Node>, start: usize) -> Vec<usize> {
+        let mut values = vec![];
+        let mut current = Some(&vec[start] as *const Node);
+        while let Some(node) = current.map(|x| unsafe { &*x }) {
+            values.push(node.value);
+            current = node.next;
+        }
+        values
+    }
+
+    fn backward(vec: &ImpVec<Node>, start: usize) -> Vec<usize> {
+        let mut values = vec![];
+        let mut current = Some(&vec[start] as *const Node);
+        while let Some(node) = current.map(|x| unsafe { &*x }) {
+            values.push(node.value);
+            current = node.prev;
+        }
+        values
+    }
+
+    let mut vec: ImpVec<Node> = ImpVec::new();
+    for value in 0..5 {
+        vec.imp_push(Node {
+            value,
+            prev: None,
+            next: None,
+        });
+    }
+    for i in 0..4 {
+        let (current, next) = (&vec[i] as *const Node, &vec[i + 1] as *const Node);
+        vec[i].next = Some(next);
+        vec[i + 1].prev = Some(current);
+    }
+    assert_eq!(forward(&vec, 0), [0, 1, 2, 3, 4]);
+    assert_eq!(backward(&vec, 4), [4, 3, 2, 1, 0]);
+
+    // middle
+    vec.unlink(2, |x| (&mut x.prev, &mut x.next));
+    assert_eq!(forward(&vec, 0), [0, 1, 3, 4]);
+    assert_eq!(backward(&vec, 4), [4, 3, 1, 0]);
+    assert!(vec[2].prev.is_none());
+    assert!(vec[2].next.is_none());
+
+    // ends
+    vec.unlink(0, |x| (&mut x.prev, &mut x.next));
+    vec.unlink(4, |x| (&mut x.prev, &mut x.next));
+    assert_eq!(forward(&vec, 1), [1, 3]);
+    assert_eq!(backward(&vec, 3), [3, 1]);
+
+    // already detached
+    vec.unlink(2, |x| (&mut x.prev, &mut x.next));
+    assert_eq!(forward(&vec, 2), [2]);
+    assert_eq!(vec.len(), 5);
+}
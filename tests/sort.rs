@@ -0,0 +1,49 @@
+use orx_imp_vec::*;
+
+#[test]
+fn argsort() {
+    fn test<P: PinnedVec<u32>>(vec: ImpVec<u32, P>) {
+        assert!(vec.argsort().is_empty());
+
+        for i in 0..100u32 {
+            vec.imp_push(i * 37 % 101);
+        }
+        let addresses: Vec<*const u32> = (0..vec.len()).map(|i| &vec[i] as *const u32).collect();
+
+        let indices = vec.argsort();
+        assert_eq!(indices.len(), 100);
+
+        let sorted: Vec<u32> = indices.iter().map(|i| vec[*i]).collect();
+        assert!(sorted.windows(2).all(|w| w[0] <= w[1]));
+
+        let mut expected: Vec<u32> = vec.iter().copied().collect();
+        expected.sort();
+        assert_eq!(sorted, expected);
+
+        for (i, address) in addresses.iter().enumerate() {
+            assert_eq!(&vec[i] as *const u32, *address);
+        }
+    }
+
+    test(ImpVec::new());
+    test(ImpVec::with_recursive_growth());
+    test(ImpVec::with_linear_growth(2));
+    test(ImpVec::with_fixed_capacity(100));
+}
+
+#[test]
+fn argsort_by() {
+    let vec = ImpVec::new();
+    vec.imp_extend_from_slice(&[(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd'), (2, 'e')]);
+
+    let ascending = vec.argsort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(ascending, [1, 3, 0, 2, 4]);
+
+    let descending = vec.argsort_by(|a, b| b.0.cmp(&a.0));
+    assert_eq!(descending, [4, 0, 2, 1, 3]);
+
+    assert_eq!(
+        &vec,
+        [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd'), (2, 'e')].as_slice()
+    );
+}